@@ -0,0 +1,167 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use crate::report::PartReport;
+
+/// Location of a year's answers file, e.g. `src/y2022/answers.toml`.
+pub fn path(year: u16) -> PathBuf {
+    crate::year_dir(year).join("answers.toml")
+}
+
+/// Accepted answers for a year's real inputs, keyed by day.
+///
+/// ```toml
+/// [07]
+/// part_one = 95437
+/// part_two = "24933642"
+/// ```
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers {
+    days: BTreeMap<u8, DayAnswers>,
+}
+
+#[derive(Debug, Default, PartialEq, Eq, Deserialize)]
+pub struct DayAnswers {
+    #[serde(default, deserialize_with = "answer")]
+    pub part_one: Option<String>,
+    #[serde(default, deserialize_with = "answer")]
+    pub part_two: Option<String>,
+}
+
+/// Answers may be written as TOML strings or integers.
+fn answer<'de, D: serde::Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Answer {
+        Text(String),
+        Number(i64),
+    }
+
+    Ok(match Answer::deserialize(deserializer)? {
+        Answer::Text(text) => Some(text),
+        Answer::Number(number) => Some(number.to_string()),
+    })
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Verdict {
+    Match,
+    Mismatch { expected: String },
+    Missing,
+}
+
+impl Answers {
+    pub fn parse(contents: &str) -> Result<Self, String> {
+        let raw: BTreeMap<String, DayAnswers> =
+            toml::from_str(contents).map_err(|e| e.to_string())?;
+        let mut days = BTreeMap::new();
+        for (key, answers) in raw {
+            let day = match key.parse::<u8>() {
+                Ok(day) if (1..=25).contains(&day) => day,
+                _ => return Err(format!("\"{}\" is not a day between 1 and 25", key)),
+            };
+            if days.insert(day, answers).is_some() {
+                return Err(format!("day {} is listed more than once", day));
+            }
+        }
+        Ok(Answers { days })
+    }
+
+    /// Reads the answers file. A missing file is treated as having no answers recorded.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => Answers::parse(&contents).map_err(io::Error::other),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Answers::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&str> {
+        let answers = self.days.get(&day)?;
+        match part {
+            1 => answers.part_one.as_deref(),
+            2 => answers.part_two.as_deref(),
+            _ => None,
+        }
+    }
+
+    pub fn verify(&self, report: &PartReport) -> Verdict {
+        match self.get(report.day, report.part) {
+            None => Verdict::Missing,
+            Some(expected) if report.answer.as_deref() == Some(expected) => Verdict::Match,
+            Some(expected) => Verdict::Mismatch {
+                expected: expected.to_owned(),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    const ANSWERS: &str = r#"
+[05]
+part_one = "CMZ"
+part_two = "MCD"
+
+[7]
+part_one = 95437
+"#;
+
+    fn report(day: u8, part: u8, answer: Option<&str>) -> PartReport {
+        PartReport {
+            year: 2022,
+            day,
+            part,
+            answer: answer.map(|a| a.to_owned()),
+            error: None,
+            elapsed: Duration::ZERO,
+            bench: None,
+        }
+    }
+
+    #[test]
+    fn test_parse() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.get(5, 2), Some("MCD"));
+        assert_eq!(answers.get(7, 1), Some("95437"));
+        assert_eq!(answers.get(7, 2), None);
+        assert_eq!(answers.get(8, 1), None);
+    }
+
+    #[test]
+    fn test_parse_invalid_day() {
+        assert!(Answers::parse("[26]\npart_one = 1").is_err());
+        assert!(Answers::parse("[day]\npart_one = 1").is_err());
+    }
+
+    #[test]
+    fn test_parse_duplicate_day() {
+        assert_eq!(
+            Answers::parse("[05]\npart_one = 1\n[5]\npart_two = 2"),
+            Err("day 5 is listed more than once".to_owned())
+        );
+    }
+
+    #[test]
+    fn test_verify() {
+        let answers = Answers::parse(ANSWERS).unwrap();
+        assert_eq!(answers.verify(&report(5, 1, Some("CMZ"))), Verdict::Match);
+        assert_eq!(
+            answers.verify(&report(7, 1, None)),
+            Verdict::Mismatch {
+                expected: "95437".to_owned()
+            }
+        );
+        assert_eq!(answers.verify(&report(7, 2, Some("1"))), Verdict::Missing);
+    }
+}
@@ -0,0 +1,119 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::process;
+
+use pico_args::Arguments;
+
+/// Where a day binary reads its input from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// `src/yYYYY/inputs/NN.txt`, the default.
+    Puzzle,
+    /// `src/yYYYY/examples/NN.txt`, selected with `--example`.
+    Example,
+    /// Any file, selected with `--input <path>`.
+    File(PathBuf),
+    /// Standard input, selected with `-` or `--input -`.
+    Stdin,
+}
+
+impl InputSource {
+    /// Reads `--input <path>`, `--example` or `-`. At most one of them may be given.
+    pub fn from_args(mut args: Arguments) -> Result<Self, String> {
+        let path: Option<PathBuf> = args
+            .opt_value_from_str("--input")
+            .map_err(|e| e.to_string())?;
+        let example = args.contains("--example");
+        let stdin = args.finish().iter().any(|arg| arg == "-");
+
+        match (path, example, stdin) {
+            (None, false, false) => Ok(InputSource::Puzzle),
+            (None, true, false) => Ok(InputSource::Example),
+            (None, false, true) => Ok(InputSource::Stdin),
+            (Some(path), false, false) if path.as_os_str() == "-" => Ok(InputSource::Stdin),
+            (Some(path), false, false) => Ok(InputSource::File(path)),
+            _ => Err("pass only one of `--input <path>`, `--example` and `-`.".to_owned()),
+        }
+    }
+
+    pub fn read(&self, year: u16, day: u8) -> io::Result<String> {
+        match self {
+            InputSource::Puzzle => crate::try_read_file(year, "inputs", day),
+            InputSource::Example => crate::try_read_file(year, "examples", day),
+            InputSource::File(path) => std::fs::read_to_string(path),
+            InputSource::Stdin => {
+                let mut input = String::new();
+                io::stdin().read_to_string(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+}
+
+impl fmt::Display for InputSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputSource::Puzzle => write!(f, "the puzzle input"),
+            InputSource::Example => write!(f, "the example"),
+            InputSource::File(path) => write!(f, "\"{}\"", path.display()),
+            InputSource::Stdin => write!(f, "stdin"),
+        }
+    }
+}
+
+/// The input source selected on the command line. Exits with a message on invalid arguments.
+pub fn input_source() -> InputSource {
+    InputSource::from_args(Arguments::from_env()).unwrap_or_else(|e| {
+        eprintln!("Failed to process arguments: {}", e);
+        process::exit(1);
+    })
+}
+
+/// The part selected with `--part 1|2`, or `None` to run both.
+/// Exits with a message on invalid arguments.
+pub fn part() -> Option<u8> {
+    match Arguments::from_env().opt_value_from_str::<_, u8>("--part") {
+        Ok(Some(part @ 1..=2)) => Some(part),
+        Ok(Some(_)) => {
+            eprintln!("`--part` has to be 1 or 2.");
+            process::exit(1);
+        }
+        Ok(None) => None,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn source(args: &[&str]) -> Result<InputSource, String> {
+        InputSource::from_args(Arguments::from_vec(
+            args.iter().map(|arg| arg.into()).collect(),
+        ))
+    }
+
+    #[test]
+    fn test_input_source() {
+        assert_eq!(source(&[]), Ok(InputSource::Puzzle));
+        assert_eq!(
+            source(&["--bench", "10", "--example"]),
+            Ok(InputSource::Example)
+        );
+        assert_eq!(source(&["-"]), Ok(InputSource::Stdin));
+        assert_eq!(source(&["--input", "-"]), Ok(InputSource::Stdin));
+        assert_eq!(
+            source(&["--part", "2", "--input", "edge.txt"]),
+            Ok(InputSource::File(PathBuf::from("edge.txt")))
+        );
+        assert!(source(&["--example", "-"]).is_err());
+    }
+}
@@ -0,0 +1,158 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::report::{PartReport, Status};
+
+pub const DEFAULT_PATH: &str = "target/aoc-bench/baseline.json";
+
+/// Allowed slowdown (in percent) before a part counts as regressed.
+pub const DEFAULT_THRESHOLD: f64 = 10.0;
+
+/// Recorded per-part timings of solved parts, as written by `--save-baseline`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Baseline {
+    pub parts: Vec<BaselineEntry>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub elapsed_ns: u64,
+}
+
+impl Baseline {
+    pub fn from_reports(reports: &[PartReport]) -> Self {
+        Baseline {
+            parts: reports
+                .iter()
+                .filter(|r| r.status() == Status::Solved)
+                .map(|r| BaselineEntry {
+                    year: r.year,
+                    day: r.day,
+                    part: r.part,
+                    elapsed_ns: r.elapsed.as_nanos() as u64,
+                })
+                .collect(),
+        }
+    }
+
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let contents = fs::read_to_string(path)?;
+        serde_json::from_str(&contents).map_err(io::Error::other)
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let contents = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(path, contents + "\n")
+    }
+
+    /// The recorded time of a part, if it was solved when the baseline was saved.
+    pub fn get(&self, year: u16, day: u8, part: u8) -> Option<Duration> {
+        self.parts
+            .iter()
+            .find(|e| e.year == year && e.day == day && e.part == part)
+            .map(|e| Duration::from_nanos(e.elapsed_ns))
+    }
+}
+
+/// A part that was timed both in the baseline and in the current run.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    pub day: u8,
+    pub part: u8,
+    pub baseline: Duration,
+    pub current: Duration,
+    pub regressed: bool,
+}
+
+impl Comparison {
+    /// Relative change against the baseline in percent; positive means slower.
+    pub fn change(&self) -> f64 {
+        let baseline = self.baseline.as_nanos() as f64;
+        if baseline == 0_f64 {
+            return 0_f64;
+        }
+        (self.current.as_nanos() as f64 - baseline) / baseline * 100_f64
+    }
+}
+
+/// Compares solved parts against `baseline`. Parts missing from either side are skipped.
+/// `threshold` is the allowed slowdown in percent.
+pub fn compare(baseline: &Baseline, reports: &[PartReport], threshold: f64) -> Vec<Comparison> {
+    reports
+        .iter()
+        .filter(|r| r.status() == Status::Solved)
+        .filter_map(|r| {
+            let previous = baseline.get(r.year, r.day, r.part)?;
+            let mut comparison = Comparison {
+                day: r.day,
+                part: r.part,
+                baseline: previous,
+                current: r.elapsed,
+                regressed: false,
+            };
+            comparison.regressed = comparison.change() > threshold;
+            Some(comparison)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(day: u8, part: u8, micros: u64) -> PartReport {
+        PartReport {
+            year: 2022,
+            day,
+            part,
+            answer: Some("0".to_owned()),
+            error: None,
+            elapsed: Duration::from_micros(micros),
+            bench: None,
+        }
+    }
+
+    #[test]
+    fn test_compare() {
+        let baseline = Baseline::from_reports(&[report(7, 1, 100), report(15, 1, 100)]);
+        let comparisons = compare(
+            &baseline,
+            &[report(7, 1, 105), report(15, 1, 120), report(21, 1, 5)],
+            DEFAULT_THRESHOLD,
+        );
+
+        assert_eq!(comparisons.len(), 2);
+        assert!(!comparisons[0].regressed);
+        assert!(comparisons[1].regressed);
+        assert!((comparisons[1].change() - 20_f64).abs() < 1.0e-6);
+    }
+
+    #[test]
+    fn test_from_reports_skips_unsolved() {
+        let mut unsolved = report(15, 2, 1);
+        unsolved.answer = None;
+        let baseline = Baseline::from_reports(&[report(15, 1, 10), unsolved]);
+        assert_eq!(
+            baseline.parts,
+            vec![BaselineEntry {
+                year: 2022,
+                day: 15,
+                part: 1,
+                elapsed_ns: 10_000
+            }]
+        );
+    }
+}
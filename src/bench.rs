@@ -0,0 +1,150 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::process;
+use std::time::Duration;
+
+use crate::report::PartReport;
+
+/// Timing statistics over repeated runs of one part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BenchStats {
+    pub runs: u32,
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub p95: Duration,
+    pub stddev: Duration,
+}
+
+impl BenchStats {
+    /// Computes statistics over `samples`, which must not be empty.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+        let n = sorted.len();
+
+        let median = if n.is_multiple_of(2) {
+            (sorted[n / 2 - 1] + sorted[n / 2]) / 2
+        } else {
+            sorted[n / 2]
+        };
+        // nearest-rank percentile.
+        let p95 = sorted[((n as f64 * 0.95).ceil() as usize).max(1) - 1];
+
+        let mean_ns = sorted.iter().map(|d| d.as_nanos() as f64).sum::<f64>() / n as f64;
+        let variance = sorted
+            .iter()
+            .map(|d| (d.as_nanos() as f64 - mean_ns).powi(2))
+            .sum::<f64>()
+            / n as f64;
+
+        BenchStats {
+            runs: n as u32,
+            min: sorted[0],
+            median,
+            mean: Duration::from_nanos(mean_ns.round() as u64),
+            p95,
+            stddev: Duration::from_nanos(variance.sqrt().round() as u64),
+        }
+    }
+}
+
+/// Number of untimed runs before measuring: a tenth of the measured runs, at least one.
+pub fn warmup_runs(iterations: u32) -> u32 {
+    iterations.div_ceil(10).max(1)
+}
+
+/// Runs `part` for warmup, then `iterations` more times.
+/// Returns the report of the last run with its `elapsed` set to the median and [`BenchStats`] attached.
+pub fn bench(mut part: impl FnMut() -> PartReport, iterations: u32) -> PartReport {
+    for _ in 0..warmup_runs(iterations) {
+        part();
+    }
+
+    let mut samples = Vec::with_capacity(iterations as usize);
+    let mut report = part();
+    samples.push(report.elapsed);
+    for _ in 1..iterations {
+        report = part();
+        samples.push(report.elapsed);
+    }
+
+    let stats = BenchStats::from_samples(&samples);
+    report.elapsed = stats.median;
+    report.bench = Some(stats);
+    report
+}
+
+/// Runs `part` once, or benchmarks it when `iterations` is given.
+pub fn run(mut part: impl FnMut() -> PartReport, iterations: Option<u32>) -> PartReport {
+    match iterations {
+        Some(iterations) => bench(part, iterations),
+        None => part(),
+    }
+}
+
+/// Reads `--bench N` from the command line. Exits if the value is not a positive integer.
+pub fn iterations_from_args() -> Option<u32> {
+    let mut args = pico_args::Arguments::from_env();
+    match args.opt_value_from_str::<_, u32>("--bench") {
+        Ok(Some(0)) => {
+            eprintln!("`--bench` needs at least one iteration.");
+            process::exit(1);
+        }
+        Ok(iterations) => iterations,
+        Err(e) => {
+            eprintln!("Failed to process arguments: {}", e);
+            process::exit(1);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|v| Duration::from_micros(*v)).collect()
+    }
+
+    #[test]
+    fn test_from_samples() {
+        let stats = BenchStats::from_samples(&micros(&[4, 2, 9, 4, 5, 5, 4, 7]));
+        assert_eq!(stats.runs, 8);
+        assert_eq!(stats.min, Duration::from_micros(2));
+        assert_eq!(
+            stats.median,
+            Duration::from_micros(4) + Duration::from_nanos(500)
+        );
+        assert_eq!(stats.mean, Duration::from_micros(5));
+        assert_eq!(stats.p95, Duration::from_micros(9));
+        assert_eq!(stats.stddev, Duration::from_micros(2));
+    }
+
+    #[test]
+    fn test_from_samples_single() {
+        let stats = BenchStats::from_samples(&micros(&[3]));
+        assert_eq!(stats.min, stats.median);
+        assert_eq!(stats.p95, Duration::from_micros(3));
+        assert_eq!(stats.stddev, Duration::ZERO);
+    }
+
+    #[test]
+    fn test_warmup_runs() {
+        assert_eq!(warmup_runs(1), 1);
+        assert_eq!(warmup_runs(10), 1);
+        assert_eq!(warmup_runs(101), 11);
+    }
+
+    #[test]
+    fn test_bench() {
+        let report = bench(
+            || crate::run_part(2022, 1, 1, |i: &str| Ok(Some(i.len())), "abc"),
+            5,
+        );
+        assert_eq!(report.answer, Some("3".to_owned()));
+        assert_eq!(report.bench.map(|b| b.runs), Some(5));
+    }
+}
@@ -2,104 +2,113 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use std::io::Write;
-use std::path::PathBuf;
-use std::{env::temp_dir, io, process::Command};
-use std::{fs, process};
+use advent_of_code::client::Client;
+use advent_of_code::puzzle;
+use std::path::Path;
+use std::{fs, io, process};
 
 struct Args {
     day: u8,
-    year: Option<i16>,
+    year: u16,
 }
 
 fn parse_args() -> Result<Args, pico_args::Error> {
     let mut args = pico_args::Arguments::from_env();
     Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(advent_of_code::YEAR),
         day: args.free_from_str()?,
-        year: args.opt_value_from_str(["-y", "--year"])?,
     })
 }
 
-fn remove_file(path: &PathBuf) {
-    #[allow(unused_must_use)]
-    {
-        fs::remove_file(path);
+fn write_file(path: &Path, contents: &str) -> io::Result<()> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
     }
+    fs::write(path, contents)
 }
 
-fn exit_with_status(status: i32, path: &PathBuf) -> ! {
-    remove_file(path);
-    process::exit(status);
+/// Examples are only written when missing or empty, so edits to them are kept.
+fn should_write_example(path: &Path) -> bool {
+    fs::read_to_string(path).map_or(true, |example| example.trim().is_empty())
 }
 
 fn main() {
-    // acquire a temp file path to write aoc-cli output to.
-    // aoc-cli expects this file not to be present - delete just in case.
-    let mut tmp_file_path = temp_dir();
-    tmp_file_path.push("aoc_input_tmp");
-    remove_file(&tmp_file_path);
-
     let args = match parse_args() {
         Ok(args) => args,
         Err(e) => {
             eprintln!("Failed to process arguments: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            process::exit(1);
         }
     };
 
-    let day_padded = format!("{:02}", args.day);
-    let input_path = format!("src/inputs/{}.txt", day_padded);
+    let year_dir = advent_of_code::year_dir(args.year);
+    let input_path = year_dir.join("inputs").join(format!("{:02}.txt", args.day));
+    let example_path = year_dir
+        .join("examples")
+        .join(format!("{:02}.txt", args.day));
+    let puzzle_path = puzzle::path(args.year, args.day);
 
-    // check if aoc binary exists and is callable.
-    if Command::new("aoc").arg("-V").output().is_err() {
-        eprintln!("command \"aoc\" not found or not callable. Try running \"cargo install aoc-cli\" to install it.");
-        exit_with_status(1, &tmp_file_path);
-    }
+    let client = match Client::from_env() {
+        Ok(client) => client,
+        Err(e) => {
+            eprintln!("Failed to set up the download: {}", e);
+            process::exit(1);
+        }
+    };
 
-    let mut cmd_args = vec![];
+    // inputs that exist in `input_path` or the cache are not downloaded again.
+    println!("Fetching input for day {}, {}...", args.day, args.year);
 
-    if let Some(year) = args.year {
-        cmd_args.push("--year".into());
-        cmd_args.push(year.to_string());
-    }
+    let input = match client.fetch_input(args.year, args.day) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to download input: {}", e);
+            process::exit(1);
+        }
+    };
 
-    cmd_args.append(&mut vec![
-        "--input-file".into(),
-        tmp_file_path.to_string_lossy().to_string(),
-        "--day".into(),
-        args.day.to_string(),
-        "download".into(),
-    ]);
+    if let Err(e) = write_file(&input_path, &input) {
+        eprintln!("Failed to write input file: {}", e);
+        process::exit(1);
+    }
 
-    println!("Downloading input with >aoc {}", cmd_args.join(" "));
+    println!("Fetching puzzle for day {}, {}...", args.day, args.year);
 
-    match Command::new("aoc").args(cmd_args).output() {
-        Ok(cmd_output) => {
-            io::stdout()
-                .write_all(&cmd_output.stdout)
-                .expect("could not write cmd stdout to pipe.");
-            io::stderr()
-                .write_all(&cmd_output.stderr)
-                .expect("could not write cmd stderr to pipe.");
-            if !cmd_output.status.success() {
-                exit_with_status(1, &tmp_file_path);
-            }
+    let page = match client.fetch_puzzle(args.year, args.day) {
+        Ok(page) => page,
+        Err(e) => {
+            eprintln!("Failed to download puzzle: {}", e);
+            process::exit(1);
         }
+    };
+
+    match write_file(&puzzle_path, &puzzle::to_markdown(&page)) {
+        Ok(_) => println!("Wrote puzzle to \"{}\"", puzzle_path.display()),
         Err(e) => {
-            eprintln!("failed to spawn aoc-cli: {}", e);
-            exit_with_status(1, &tmp_file_path);
+            eprintln!("Failed to write puzzle file: {}", e);
+            process::exit(1);
         }
     }
 
-    match fs::copy(&tmp_file_path, &input_path) {
-        Ok(_) => {
-            println!("---");
-            println!("🎄 Successfully wrote input to \"{}\".", &input_path);
-            exit_with_status(0, &tmp_file_path);
-        }
-        Err(e) => {
-            eprintln!("could not copy downloaded input to input file: {}", e);
-            exit_with_status(1, &tmp_file_path);
+    match puzzle::example(&page) {
+        Some(example) if should_write_example(&example_path) => {
+            match write_file(&example_path, &example) {
+                Ok(_) => println!("Wrote example to \"{}\"", example_path.display()),
+                Err(e) => {
+                    eprintln!("Failed to write example file: {}", e);
+                    process::exit(1);
+                }
+            }
         }
+        Some(_) => println!("Kept existing example \"{}\"", example_path.display()),
+        None => println!("No example found in the puzzle."),
     }
+
+    println!("---");
+    println!(
+        "🎄 Successfully wrote input to \"{}\".",
+        input_path.display()
+    );
 }
@@ -3,112 +3,801 @@
  * There is no need to edit this file unless you want to change template functionality.
  */
 use std::{
-    fs::{File, OpenOptions},
-    io::Write,
+    fmt, fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
     process,
 };
 
-const MODULE_TEMPLATE: &str = r###"pub fn part_one(input: &str) -> Option<u32> {
-    None
+use advent_of_code::readme;
+use advent_of_code::template::{self, Placeholders, Template};
+
+struct Args {
+    day: u8,
+    year: u16,
+    template: String,
+    answers: (String, String),
+    force: bool,
+    dry_run: bool,
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    None
+fn parse_args() -> Result<Args, pico_args::Error> {
+    let mut args = pico_args::Arguments::from_env();
+    Ok(Args {
+        year: args
+            .opt_value_from_str(["-y", "--year"])?
+            .unwrap_or(advent_of_code::YEAR),
+        template: args
+            .opt_value_from_str("--template")?
+            .unwrap_or_else(|| template::DEFAULT_VARIANT.to_owned()),
+        answers: args
+            .opt_value_from_fn("--answer", template::answer_types)?
+            .unwrap_or_else(|| {
+                (
+                    template::DEFAULT_ANSWER.to_owned(),
+                    template::DEFAULT_ANSWER.to_owned(),
+                )
+            }),
+        force: args.contains("--force"),
+        dry_run: args.contains("--dry-run"),
+        day: args.free_from_fn(parse_day)?,
+    })
 }
 
-fn main() {
-    let input = &advent_of_code::read_file("inputs", DAY);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+fn parse_day(value: &str) -> Result<u8, String> {
+    match value.parse() {
+        Ok(day) if (1..=25).contains(&day) => Ok(day),
+        _ => Err(format!("\"{}\" is not a day between 1 and 25", value)),
+    }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+/// How an artifact compares to what scaffolding would make of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Existing {
+    Missing,
+    Same,
+    Different,
+}
 
-    #[test]
-    fn test_part_one() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_one(&input), None);
+/// What scaffolding does with an artifact.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Change {
+    Create,
+    Overwrite,
+    Skip,
+    /// The artifact differs, but is only replaced with `--force`.
+    Conflict,
+}
+
+/// One thing a day is made of: a file, or an entry in a file shared with other days.
+struct Artifact {
+    /// e.g. `module` or `registry entry in`, followed by the path when printed.
+    name: &'static str,
+    path: PathBuf,
+    original: Option<String>,
+    contents: String,
+    existing: Existing,
+    /// Whether `--force` may replace contents that differ. Inputs and examples are never replaced.
+    overwritable: bool,
+}
+
+impl Artifact {
+    /// A whole file, compared to its current contents.
+    fn file(name: &'static str, path: PathBuf, contents: String) -> io::Result<Self> {
+        let original = read_optional(&path)?;
+        let existing = match &original {
+            None => Existing::Missing,
+            Some(current) if *current == contents => Existing::Same,
+            Some(_) => Existing::Different,
+        };
+        Ok(Artifact {
+            name,
+            path,
+            original,
+            contents,
+            existing,
+            overwritable: true,
+        })
     }
 
-    #[test]
-    fn test_part_two() {
-        let input = advent_of_code::read_file("examples", DAY);
-        assert_eq!(part_two(&input), None);
+    fn change(&self, force: bool) -> Change {
+        match self.existing {
+            Existing::Missing => Change::Create,
+            Existing::Same => Change::Skip,
+            Existing::Different if !self.overwritable => Change::Skip,
+            Existing::Different if force => Change::Overwrite,
+            Existing::Different => Change::Conflict,
+        }
     }
 }
-"###;
 
-fn parse_args() -> Result<u8, pico_args::Error> {
-    let mut args = pico_args::Arguments::from_env();
-    args.free_from_str()
+impl fmt::Display for Artifact {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} \"{}\"", self.name, self.path.display())
+    }
 }
 
-fn safe_create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create_new(true).open(path)
+fn read_optional(path: &Path) -> io::Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e),
+    }
 }
 
-fn create_file(path: &str) -> Result<File, std::io::Error> {
-    OpenOptions::new().write(true).create(true).open(path)
+/// Declares `module`, loaded from `file`, in `contents`, keeping the declarations ordered by name.
+fn declare_module(contents: &str, file: &str, module: &str) -> (Existing, String) {
+    let mut lines: Vec<String> = contents.lines().map(|l| l.to_owned()).collect();
+
+    let declaration = format!("pub mod {};", module);
+    if lines.contains(&declaration) {
+        return (Existing::Same, contents.to_owned());
+    }
+
+    let index = lines
+        .iter()
+        .position(|l| l.starts_with("pub mod ") && l.as_str() > declaration.as_str())
+        .map(|i| i - 1)
+        .or_else(|| {
+            lines
+                .iter()
+                .rposition(|l| l.starts_with("pub mod "))
+                .map(|i| i + 1)
+        })
+        .unwrap_or_else(|| lines.iter().take_while(|l| l.starts_with("//!")).count());
+    lines.splice(
+        index..index,
+        [format!("#[path = \"{}\"]", file), declaration],
+    );
+
+    (Existing::Missing, lines.join("\n") + "\n")
 }
 
-fn main() {
-    let day = match parse_args() {
-        Ok(day) => day,
-        Err(_) => {
-            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
-            process::exit(1);
+/// Adds the day's `solution!` entry to the all-days runner, ordered by year and day.
+/// An existing entry of the day is replaced when it differs.
+fn register_solution(
+    runner: &str,
+    year: u16,
+    day: u8,
+    entry: &str,
+) -> io::Result<(Existing, String)> {
+    let mut lines: Vec<String> = runner.lines().map(|l| l.to_owned()).collect();
+    let entry = format!("        {}", entry.trim());
+
+    let entries_start = find_line(&lines, |l| l.starts_with("fn solutions()"))?;
+    let entries_end = entries_start + find_line(&lines[entries_start..], |l| l == "    ]")?;
+    let existing = lines[entries_start..entries_end]
+        .iter()
+        .position(|l| entry_key(l) == Some((year, day)))
+        .map(|i| i + entries_start);
+
+    match existing {
+        Some(index) if lines[index] == entry => return Ok((Existing::Same, runner.to_owned())),
+        Some(index) => lines[index] = entry,
+        None => {
+            let entry_index = lines[entries_start..entries_end]
+                .iter()
+                .position(|l| entry_key(l).is_some_and(|key| key > (year, day)))
+                .map(|i| i + entries_start)
+                .unwrap_or(entries_end);
+            lines.insert(entry_index, entry);
         }
+    }
+
+    let state = if existing.is_some() {
+        Existing::Different
+    } else {
+        Existing::Missing
+    };
+    Ok((state, lines.join("\n") + "\n"))
+}
+
+fn find_line(lines: &[String], predicate: impl Fn(&str) -> bool) -> io::Result<usize> {
+    lines
+        .iter()
+        .position(|l| predicate(l))
+        .ok_or_else(|| io::Error::other("unexpected layout of the runner"))
+}
+
+/// Year and day of a `solution!(year, day, module)` line.
+fn entry_key(line: &str) -> Option<(u16, u8)> {
+    let mut args = line.trim().strip_prefix("solution!(")?.split(',');
+    let year = args.next()?.trim().parse().ok()?;
+    let day = args.next()?.trim().parse().ok()?;
+    Some((year, day))
+}
+
+/// Declares the day's binary in `Cargo.toml`, so `cargo solve` can run it.
+fn register_binary(manifest: &str, name: &str, bin_path: &Path) -> (Existing, String) {
+    let path = format!("path = \"{}\"", bin_path.display());
+
+    if manifest.lines().any(|l| l == path) {
+        return (Existing::Same, manifest.to_owned());
+    }
+
+    let mut manifest = manifest.to_owned();
+    manifest.push_str(&format!("\n[[bin]]\nname = \"{}\"\n{}\n", name, path));
+    (Existing::Missing, manifest)
+}
+
+/// An entry that `update` adds to the file at `path`, which has to exist unless `default` is given.
+fn entry(
+    name: &'static str,
+    path: PathBuf,
+    default: Option<&str>,
+    update: impl FnOnce(&str) -> Result<(Existing, String), String>,
+) -> Result<Artifact, String> {
+    let original = read_optional(&path).map_err(|e| format!("\"{}\": {}", path.display(), e))?;
+    let current = match (&original, default) {
+        (Some(current), _) => current.as_str(),
+        (None, Some(default)) => default,
+        (None, None) => return Err(format!("\"{}\" does not exist", path.display())),
     };
+    let (existing, contents) =
+        update(current).map_err(|e| format!("\"{}\": {}", path.display(), e))?;
+    Ok(Artifact {
+        name,
+        path,
+        existing: if original.is_none() {
+            Existing::Missing
+        } else {
+            existing
+        },
+        original,
+        contents,
+        overwritable: true,
+    })
+}
 
+/// Everything a day is made of, in the order it is written. Paths are relative to `root`.
+fn plan(args: &Args, root: &Path, template: &Template) -> Result<Vec<Artifact>, String> {
+    let Args { year, day, .. } = *args;
     let day_padded = format!("{:02}", day);
+    let year_module = format!("y{}", year);
+    let day_module = format!("day{}", day_padded);
+    let year_dir = root.join(advent_of_code::year_dir(year));
+
+    let title = fs::read_to_string(root.join(advent_of_code::puzzle::path(year, day)))
+        .ok()
+        .and_then(|markdown| advent_of_code::puzzle::title(&markdown))
+        .unwrap_or_else(|| template::UNTITLED.to_owned());
+    let placeholders = Placeholders {
+        year,
+        day,
+        title,
+        answer_one: args.answers.0.clone(),
+        answer_two: args.answers.1.clone(),
+    };
+    let render = |text: &str| template::render(text, &placeholders);
+    let file = |name, path, contents| {
+        Artifact::file(name, path, contents).map_err(|e| format!("{}: {}", name, e))
+    };
+
+    let bin_path = advent_of_code::year_dir(year)
+        .join("bin")
+        .join(format!("{}.rs", day_padded));
+    let bin_name = advent_of_code::bin_name(year, day);
+    let solution_entry = render(&template.solution)?;
+
+    let mut artifacts = vec![
+        file(
+            "module",
+            year_dir.join("days").join(format!("{}.rs", day_padded)),
+            render(&template.module)?,
+        )?,
+        file("binary", root.join(&bin_path), render(&template.bin)?)?,
+        entry(
+            "module declaration in",
+            year_dir.join("days.rs"),
+            Some(&format!(
+                "//! The solutions of {}, one module per day.\n",
+                year
+            )),
+            |contents| {
+                Ok(declare_module(
+                    contents,
+                    &format!("days/{}.rs", day_padded),
+                    &day_module,
+                ))
+            },
+        )?,
+        entry(
+            "year declaration in",
+            root.join("src/days.rs"),
+            Some(""),
+            |contents| {
+                Ok(declare_module(
+                    contents,
+                    &format!("{}/days.rs", year_module),
+                    &year_module,
+                ))
+            },
+        )?,
+        entry(
+            "binary entry in",
+            root.join("Cargo.toml"),
+            None,
+            |manifest| Ok(register_binary(manifest, &bin_name, &bin_path)),
+        )?,
+        entry(
+            "registry entry in",
+            root.join("src/main.rs"),
+            None,
+            |runner| {
+                register_solution(runner, year, day, &solution_entry).map_err(|e| e.to_string())
+            },
+        )?,
+        entry("README row in", root.join("README.md"), None, |contents| {
+            Ok(match readme::add_row(contents, year, day)? {
+                Some(updated) => (Existing::Missing, updated),
+                None => (Existing::Same, contents.to_owned()),
+            })
+        })?,
+    ];
+
+    for (name, folder) in [("input", "inputs"), ("example", "examples")] {
+        let mut artifact = file(
+            name,
+            year_dir.join(folder).join(format!("{}.txt", day_padded)),
+            String::new(),
+        )?;
+        artifact.overwritable = false;
+        artifacts.push(artifact);
+    }
+
+    Ok(artifacts)
+}
+
+/// Something written by [`apply`], and how to undo it.
+enum Undo {
+    Remove(PathBuf),
+    Restore(PathBuf, String),
+    RemoveDir(PathBuf),
+}
 
-    let input_path = format!("src/inputs/{}.txt", day_padded);
-    let example_path = format!("src/examples/{}.txt", day_padded);
-    let module_path = format!("src/bin/{}.rs", day_padded);
+/// Writes every artifact that is created or overwritten. When a write fails,
+/// everything written before is undone and the error is returned.
+fn apply(artifacts: &[Artifact], force: bool) -> Result<(), String> {
+    let mut journal: Vec<Undo> = Vec::new();
 
-    let mut file = match safe_create_file(&module_path) {
-        Ok(file) => file,
+    for artifact in artifacts {
+        let change = artifact.change(force);
+        if !matches!(change, Change::Create | Change::Overwrite) {
+            continue;
+        }
+        if let Err(e) = write(artifact, &mut journal) {
+            let undone = journal.len();
+            rollback(journal);
+            return Err(format!(
+                "Failed to write {}: {}. Undid {} earlier change(s).",
+                artifact, e, undone
+            ));
+        }
+    }
+
+    Ok(())
+}
+
+fn write(artifact: &Artifact, journal: &mut Vec<Undo>) -> io::Result<()> {
+    if let Some(parent) = artifact.path.parent() {
+        let mut missing: Vec<&Path> = parent
+            .ancestors()
+            .take_while(|dir| !dir.as_os_str().is_empty() && !dir.exists())
+            .collect();
+        missing.reverse();
+        for dir in missing {
+            fs::create_dir(dir)?;
+            journal.push(Undo::RemoveDir(dir.to_path_buf()));
+        }
+    }
+
+    fs::write(&artifact.path, &artifact.contents)?;
+    journal.push(match &artifact.original {
+        Some(original) => Undo::Restore(artifact.path.clone(), original.clone()),
+        None => Undo::Remove(artifact.path.clone()),
+    });
+    Ok(())
+}
+
+fn rollback(journal: Vec<Undo>) {
+    for undo in journal.into_iter().rev() {
+        let result = match &undo {
+            Undo::Remove(path) => fs::remove_file(path),
+            Undo::Restore(path, original) => fs::write(path, original),
+            Undo::RemoveDir(path) => fs::remove_dir(path),
+        };
+        if let Err(e) = result {
+            let path = match &undo {
+                Undo::Remove(path) | Undo::Restore(path, _) | Undo::RemoveDir(path) => path,
+            };
+            eprintln!("Failed to undo changes to \"{}\": {}", path.display(), e);
+        }
+    }
+}
+
+/// Whether any of `artifacts` differs from the template and may not be replaced.
+fn conflicts(artifacts: &[Artifact], force: bool) -> bool {
+    artifacts
+        .iter()
+        .any(|artifact| artifact.change(force) == Change::Conflict)
+}
+
+/// Plans the day in `root` and writes it, unless this is a dry run or a file conflicts.
+fn scaffold(args: &Args, root: &Path) -> Result<Vec<Artifact>, String> {
+    let artifacts = Template::load(&root.join(template::DIR), &args.template)
+        .and_then(|template| plan(args, root, &template))
+        .map_err(|e| format!("Failed to scaffold day {}: {}", args.day, e))?;
+    if !args.dry_run && !conflicts(&artifacts, args.force) {
+        apply(&artifacts, args.force)?;
+    }
+    Ok(artifacts)
+}
+
+fn describe(change: Change, dry_run: bool) -> &'static str {
+    match (change, dry_run) {
+        (Change::Create, false) => "Created",
+        (Change::Create, true) => "Would create",
+        (Change::Overwrite, false) => "Overwrote",
+        (Change::Overwrite | Change::Conflict, _) => "Would overwrite",
+        (Change::Skip, _) => "Skipped",
+    }
+}
+
+fn main() {
+    let args = match parse_args() {
+        Ok(args) => args,
+        Err(pico_args::Error::MissingArgument) => {
+            eprintln!("Need to specify a day (as integer). example: `cargo scaffold 7`");
+            process::exit(1);
+        }
         Err(e) => {
-            eprintln!("Failed to create module file: {}", e);
+            eprintln!("Failed to process arguments: {}", e);
             process::exit(1);
         }
     };
 
-    match file.write_all(MODULE_TEMPLATE.replace("DAY", &day.to_string()).as_bytes()) {
-        Ok(_) => {
-            println!("Created module file \"{}\"", &module_path);
-        }
+    let artifacts = match scaffold(&args, Path::new("")) {
+        Ok(artifacts) => artifacts,
         Err(e) => {
-            eprintln!("Failed to write module contents: {}", e);
+            eprintln!("{}", e);
             process::exit(1);
         }
+    };
+
+    // A conflict stops `scaffold` from writing anything, like a dry run.
+    let conflict = conflicts(&artifacts, args.force);
+    let dry_run = args.dry_run || conflict;
+    for artifact in &artifacts {
+        let change = artifact.change(args.force);
+        match change {
+            Change::Conflict => println!(
+                "{} {} (differs from the template, pass --force to replace it)",
+                describe(change, dry_run),
+                artifact
+            ),
+            Change::Skip if artifact.existing == Existing::Different => println!(
+                "{} {} (already has contents)",
+                describe(change, dry_run),
+                artifact
+            ),
+            _ => println!("{} {}", describe(change, dry_run), artifact),
+        }
+    }
+
+    println!("---");
+    if conflict {
+        println!(
+            "🎄 Some files differ from the template, nothing was written. Pass --force to replace them."
+        );
+        process::exit(1);
+    } else if args.dry_run {
+        println!("🎄 Dry run, nothing was written.");
+    } else {
+        println!(
+            "🎄 Type `cargo solve {}` to run your solution.",
+            advent_of_code::bin_name(args.year, args.day)
+        );
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
 
-    match create_file(&input_path) {
-        Ok(_) => {
-            println!("Created empty input file \"{}\"", &input_path);
+    const README: &str = "<!--- advent_readme_stars table --->
+## 2022 Results
+
+| Day | Part 1 | Part 2 |
+| :---: | :---: | :---: |
+| [Day 1](https://adventofcode.com/2022/day/1) | ⭐ | ⭐ |
+<!--- advent_readme_stars table --->
+";
+
+    const RUNNER: &str = "fn solutions() -> Vec<Solution> {
+    vec![
+        solution!(2022, 1, days::y2022::day01),
+        solution!(2022, 9, days::y2022::day09),
+    ]
+}
+";
+
+    /// A repository with day 1 of 2022 and a minimal default template, in a fresh directory.
+    fn repository(name: &str) -> PathBuf {
+        let root = env::temp_dir().join(format!("aoc-scaffold-test-{}-{}", process::id(), name));
+        let _ = fs::remove_dir_all(&root);
+        for (path, contents) in [
+            ("Cargo.toml", "[package]\nname = \"advent_of_code\"\n"),
+            ("README.md", README),
+            ("src/main.rs", RUNNER),
+            ("src/days.rs", "#[path = \"y2022/days.rs\"]\npub mod y2022;\n"),
+            (
+                "src/y2022/days.rs",
+                "//! The solutions of 2022, one module per day.\n#[path = \"days/01.rs\"]\npub mod day01;\n",
+            ),
+            ("templates/default/day.rs", "// Day {{day}}: {{title}}\n"),
+            ("templates/default/bin.rs", "// day{{day_padded}}\n"),
+            (
+                "templates/default/solution.txt",
+                "solution!({{year}}, {{day}}, days::y{{year}}::day{{day_padded}}),\n",
+            ),
+        ] {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
         }
-        Err(e) => {
-            eprintln!("Failed to create input file: {}", e);
-            process::exit(1);
+        root
+    }
+
+    fn args(day: u8, force: bool, dry_run: bool) -> Args {
+        Args {
+            day,
+            year: 2022,
+            template: template::DEFAULT_VARIANT.to_owned(),
+            answers: ("u32".to_owned(), "u32".to_owned()),
+            force,
+            dry_run,
         }
     }
 
-    match create_file(&example_path) {
-        Ok(_) => {
-            println!("Created empty example file \"{}\"", &example_path);
+    fn changes(artifacts: &[Artifact], force: bool) -> Vec<Change> {
+        artifacts.iter().map(|a| a.change(force)).collect()
+    }
+
+    fn read(root: &Path, path: &str) -> String {
+        fs::read_to_string(root.join(path)).unwrap()
+    }
+
+    #[test]
+    fn test_parse_day() {
+        assert_eq!(parse_day("7"), Ok(7));
+        assert_eq!(parse_day("25"), Ok(25));
+        assert!(parse_day("0").is_err());
+        assert!(parse_day("99").is_err());
+        assert!(parse_day("x").is_err());
+    }
+
+    #[test]
+    fn test_declare_module() {
+        let days = "//! Days.\n#[path = \"days/01.rs\"]\npub mod day01;\n#[path = \"days/09.rs\"]\npub mod day09;\n";
+
+        assert_eq!(
+            declare_module(days, "days/01.rs", "day01"),
+            (Existing::Same, days.to_owned())
+        );
+        assert_eq!(
+            declare_module(days, "days/05.rs", "day05"),
+            (
+                Existing::Missing,
+                "//! Days.\n#[path = \"days/01.rs\"]\npub mod day01;\n#[path = \"days/05.rs\"]\npub mod day05;\n#[path = \"days/09.rs\"]\npub mod day09;\n".to_owned()
+            )
+        );
+        assert_eq!(
+            declare_module("//! Days.\n", "days/01.rs", "day01"),
+            (
+                Existing::Missing,
+                "//! Days.\n#[path = \"days/01.rs\"]\npub mod day01;\n".to_owned()
+            )
+        );
+    }
+
+    #[test]
+    fn test_register_solution() {
+        let (existing, runner) =
+            register_solution(RUNNER, 2022, 1, "solution!(2022, 1, days::y2022::day01),").unwrap();
+        assert_eq!((existing, runner.as_str()), (Existing::Same, RUNNER));
+
+        let (existing, runner) =
+            register_solution(RUNNER, 2022, 5, "solution!(2022, 5, days::y2022::day05),").unwrap();
+        assert_eq!(existing, Existing::Missing);
+        assert_eq!(
+            runner.lines().filter_map(entry_key).collect::<Vec<_>>(),
+            [(2022, 1), (2022, 5), (2022, 9)]
+        );
+
+        let entry = "solution!(2022, 9, days::y2022::day09::Day09 as Day),";
+        let (existing, runner) = register_solution(RUNNER, 2022, 9, entry).unwrap();
+        assert_eq!(existing, Existing::Different);
+        assert!(runner.contains(entry));
+        assert!(!runner.contains("days::y2022::day09),"));
+
+        assert!(register_solution("fn main() {}\n", 2022, 1, entry).is_err());
+    }
+
+    #[test]
+    fn test_register_binary() {
+        let manifest = "[package]\n\n[[bin]]\nname = \"01\"\npath = \"src/y2022/bin/01.rs\"\n";
+
+        assert_eq!(
+            register_binary(manifest, "01", Path::new("src/y2022/bin/01.rs")),
+            (Existing::Same, manifest.to_owned())
+        );
+        assert_eq!(
+            register_binary(manifest, "02", Path::new("src/y2022/bin/02.rs")),
+            (
+                Existing::Missing,
+                format!(
+                    "{}\n[[bin]]\nname = \"02\"\npath = \"src/y2022/bin/02.rs\"\n",
+                    manifest
+                )
+            )
+        );
+    }
+
+    #[test]
+    fn test_scaffold() {
+        let root = repository("scaffold");
+
+        let artifacts = scaffold(&args(5, false, false), &root).unwrap();
+        assert!(changes(&artifacts, false)
+            .iter()
+            .all(|c| *c == Change::Create || *c == Change::Skip));
+        assert_eq!(read(&root, "src/y2022/days/05.rs"), "// Day 5: Untitled\n");
+        assert_eq!(read(&root, "src/y2022/inputs/05.txt"), "");
+        assert!(read(&root, "src/main.rs").contains("solution!(2022, 5, days::y2022::day05),"));
+        assert!(read(&root, "Cargo.toml").contains("path = \"src/y2022/bin/05.rs\""));
+
+        // everything is registered already, so a second run changes nothing.
+        let before = read(&root, "src/main.rs") + &read(&root, "Cargo.toml");
+        let artifacts = scaffold(&args(5, false, false), &root).unwrap();
+        assert!(changes(&artifacts, false)
+            .iter()
+            .all(|c| *c == Change::Skip));
+        assert_eq!(
+            before,
+            read(&root, "src/main.rs") + &read(&root, "Cargo.toml")
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_scaffold_dry_run() {
+        let root = repository("dry-run");
+        let main = read(&root, "src/main.rs");
+
+        let artifacts = scaffold(&args(5, false, true), &root).unwrap();
+        assert!(changes(&artifacts, false).contains(&Change::Create));
+        assert!(!root.join("src/y2022/days/05.rs").exists());
+        assert!(!root.join("src/y2022/inputs").exists());
+        assert_eq!(read(&root, "src/main.rs"), main);
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_scaffold_force() {
+        let root = repository("force");
+        scaffold(&args(5, false, false), &root).unwrap();
+        fs::write(root.join("src/y2022/days/05.rs"), "// solved\n").unwrap();
+        fs::write(root.join("src/y2022/inputs/05.txt"), "1\n").unwrap();
+        fs::remove_file(root.join("src/y2022/examples/05.txt")).unwrap();
+
+        // a conflict leaves everything as it was, including files that could be created.
+        let artifacts = scaffold(&args(5, false, false), &root).unwrap();
+        assert_eq!(changes(&artifacts, false)[0], Change::Conflict);
+        assert_eq!(read(&root, "src/y2022/days/05.rs"), "// solved\n");
+        assert!(!root.join("src/y2022/examples/05.txt").exists());
+
+        let artifacts = scaffold(&args(5, true, false), &root).unwrap();
+        assert_eq!(changes(&artifacts, true)[0], Change::Overwrite);
+        assert_eq!(read(&root, "src/y2022/days/05.rs"), "// Day 5: Untitled\n");
+        // inputs are never replaced, not even with --force.
+        assert_eq!(read(&root, "src/y2022/inputs/05.txt"), "1\n");
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_apply_rolls_back() {
+        let root = repository("rollback");
+        let main = read(&root, "src/main.rs");
+        let template = Template::load(&root.join(template::DIR), "default").unwrap();
+
+        let mut artifacts = plan(&args(5, false, false), &root, &template).unwrap();
+        // README.md is a file, so nothing can be written below it.
+        let broken = Artifact {
+            name: "broken",
+            path: root.join("README.md/x"),
+            original: None,
+            contents: String::new(),
+            existing: Existing::Missing,
+            overwritable: true,
+        };
+        artifacts.insert(6, broken);
+
+        assert!(apply(&artifacts, false).is_err());
+        assert!(!root.join("src/y2022/days/05.rs").exists());
+        assert!(!root.join("src/y2022/bin").exists());
+        assert_eq!(read(&root, "src/main.rs"), main);
+        assert!(!read(&root, "Cargo.toml").contains("05.rs"));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    /// Copies the sources, manifests and templates of this repository to a fresh directory.
+    fn checkout(name: &str) -> PathBuf {
+        fn copy(from: &Path, to: &Path) {
+            fs::create_dir_all(to).unwrap();
+            for entry in fs::read_dir(from).unwrap() {
+                let entry = entry.unwrap();
+                let target = to.join(entry.file_name());
+                if entry.file_type().unwrap().is_dir() {
+                    copy(&entry.path(), &target);
+                } else {
+                    fs::copy(entry.path(), target).unwrap();
+                }
+            }
         }
-        Err(e) => {
-            eprintln!("Failed to create example file: {}", e);
-            process::exit(1);
+
+        let repository = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let root = env::temp_dir().join(format!("aoc-scaffold-test-{}-{}", process::id(), name));
+        let _ = fs::remove_dir_all(&root);
+        for dir in ["src", template::DIR] {
+            copy(&repository.join(dir), &root.join(dir));
+        }
+        for file in ["Cargo.toml", "Cargo.lock", "README.md"] {
+            if repository.join(file).exists() {
+                fs::copy(repository.join(file), root.join(file)).unwrap();
+            }
         }
+        root
     }
 
-    println!("---");
-    println!(
-        "🎄 Type `cargo solve {}` to run your solution.",
-        &day_padded
-    );
+    #[test]
+    fn test_variants_pass_clippy() {
+        let root = checkout("clippy");
+        let variants = template::variants(&root.join(template::DIR));
+        for (day, variant) in (22..).zip(&variants) {
+            let args = Args {
+                template: variant.clone(),
+                ..args(day, false, false)
+            };
+            scaffold(&args, &root).unwrap();
+        }
+
+        // Shares a target directory across runs, so only the scaffolded days are rebuilt.
+        let target = Path::new(env!("CARGO_MANIFEST_DIR")).join("target/scaffold-check");
+        let output = process::Command::new(env!("CARGO"))
+            .args([
+                "clippy",
+                "--offline",
+                "--all-targets",
+                "--",
+                "-D",
+                "warnings",
+            ])
+            .current_dir(&root)
+            .env("CARGO_TARGET_DIR", target)
+            .output()
+            .unwrap();
+        assert!(
+            output.status.success(),
+            "scaffolding {} fails clippy:\n{}",
+            variants.join(", "),
+            String::from_utf8_lossy(&output.stderr)
+        );
+
+        fs::remove_dir_all(&root).unwrap();
+    }
 }
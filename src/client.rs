@@ -0,0 +1,426 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::cell::Cell;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Overrides [`DEFAULT_BASE_URL`], e.g. to point the client at a local mock server.
+pub const BASE_URL_ENV: &str = "AOC_BASE_URL";
+
+/// Holds the session cookie. Takes precedence over the session file.
+pub const SESSION_ENV: &str = "AOC_SESSION";
+
+/// Name of the session file in the home directory, shared with `aoc-cli`.
+pub const SESSION_FILE: &str = ".adventofcode.session";
+
+/// Overrides [`DEFAULT_USER_AGENT`]. The puzzle site asks for a way to contact you in it.
+pub const USER_AGENT_ENV: &str = "AOC_USER_AGENT";
+
+pub const DEFAULT_USER_AGENT: &str = concat!("advent_of_code/", env!("CARGO_PKG_VERSION"));
+
+/// Where downloaded inputs and finished puzzle pages are kept.
+pub const DEFAULT_CACHE_DIR: &str = "target/aoc-cache";
+
+/// Minimum time between two requests to the puzzle site, also across runs sharing a cache.
+pub const DEFAULT_THROTTLE: Duration = Duration::from_secs(3);
+
+/// Location of the session file, if a home directory is known.
+pub fn session_file() -> Option<PathBuf> {
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(SESSION_FILE))
+}
+
+/// Reads the session cookie from `AOC_SESSION` or, failing that, from `~/.adventofcode.session`.
+pub fn load_session() -> Result<String, String> {
+    if let Ok(session) = env::var(SESSION_ENV) {
+        if !session.trim().is_empty() {
+            return Ok(session.trim().to_owned());
+        }
+    }
+
+    let path = session_file().ok_or("could not determine the home directory")?;
+    match fs::read_to_string(&path) {
+        Ok(session) if !session.trim().is_empty() => Ok(session.trim().to_owned()),
+        Ok(_) => Err(format!("session file \"{}\" is empty", path.display())),
+        Err(_) => Err(format!(
+            "no session cookie found. Set {} or write it to \"{}\"",
+            SESSION_ENV,
+            path.display()
+        )),
+    }
+}
+
+/// Days since 1970-01-01 of a date in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Puzzles unlock at midnight EST (UTC-5) on their day in December.
+pub fn unlock_time(year: u16, day: u8) -> SystemTime {
+    let days = days_from_civil(year as i64, 12, day as i64);
+    UNIX_EPOCH + Duration::from_secs((days * 86_400 + 5 * 3_600) as u64)
+}
+
+/// Refuses days that don't exist or haven't unlocked at `now`.
+pub fn check_unlocked(year: u16, day: u8, now: SystemTime) -> Result<(), String> {
+    if !(1..=25).contains(&day) {
+        return Err(format!("day {} is not a day between 1 and 25", day));
+    }
+    match unlock_time(year, day).duration_since(now) {
+        Ok(wait) if !wait.is_zero() => {
+            let secs = wait.as_secs();
+            Err(format!(
+                "day {} of {} unlocks in {}h {}m {}s",
+                day,
+                year,
+                secs / 3_600,
+                secs % 3_600 / 60,
+                secs % 60
+            ))
+        }
+        _ => Ok(()),
+    }
+}
+
+/// A logged-in client for the Advent of Code website.
+///
+/// All requests are throttled and sent with a User-Agent. Inputs that already exist in
+/// `src/yYYYY/inputs` are never requested again, and with a cache directory, downloaded inputs and
+/// puzzle pages with both parts unlocked are reused.
+pub struct Client {
+    base_url: String,
+    session: String,
+    user_agent: String,
+    throttle: Duration,
+    cache_dir: Option<PathBuf>,
+    last_request: Cell<Option<SystemTime>>,
+    agent: ureq::Agent,
+}
+
+impl Client {
+    pub fn new(base_url: &str, session: &str) -> Self {
+        Client {
+            base_url: base_url.trim_end_matches('/').to_owned(),
+            session: session.to_owned(),
+            user_agent: DEFAULT_USER_AGENT.to_owned(),
+            throttle: DEFAULT_THROTTLE,
+            cache_dir: None,
+            last_request: Cell::new(None),
+            agent: ureq::Agent::new(),
+        }
+    }
+
+    /// Builds a client from `AOC_BASE_URL` and `AOC_USER_AGENT` (both optional) and the session cookie,
+    /// see [`load_session`]. Caches in [`DEFAULT_CACHE_DIR`].
+    pub fn from_env() -> Result<Self, String> {
+        let base_url = env::var(BASE_URL_ENV).unwrap_or_else(|_| DEFAULT_BASE_URL.to_owned());
+        let mut client = Client::new(&base_url, &load_session()?).with_cache(DEFAULT_CACHE_DIR);
+        if let Ok(user_agent) = env::var(USER_AGENT_ENV) {
+            client = client.with_user_agent(&user_agent);
+        }
+        Ok(client)
+    }
+
+    pub fn with_user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = user_agent.to_owned();
+        self
+    }
+
+    pub fn with_throttle(mut self, throttle: Duration) -> Self {
+        self.throttle = throttle;
+        self
+    }
+
+    pub fn with_cache(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.cache_dir = Some(dir.into());
+        self
+    }
+
+    pub fn input_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}/input", self.base_url, year, day)
+    }
+
+    pub fn puzzle_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}", self.base_url, year, day)
+    }
+
+    pub fn answer_url(&self, year: u16, day: u8) -> String {
+        format!("{}/{}/day/{}/answer", self.base_url, year, day)
+    }
+
+    /// The puzzle input of `day` in `year`, from `src/yYYYY/inputs`, the cache or the puzzle site.
+    pub fn fetch_input(&self, year: u16, day: u8) -> Result<String, String> {
+        check_unlocked(year, day, SystemTime::now())?;
+
+        if let Ok(input) = crate::try_read_file(year, "inputs", day) {
+            if !input.is_empty() {
+                return Ok(input);
+            }
+        }
+        if let Some(input) = self.read_cache(year, day, "input") {
+            return Ok(input);
+        }
+
+        let input = self.get(&self.input_url(year, day))?;
+        self.write_cache(year, day, "input", &input);
+        Ok(input)
+    }
+
+    /// The puzzle page of `day` in `year`, see [`crate::puzzle`] to extract its contents.
+    /// Pages are only cached once part two is unlocked, as they don't change afterwards.
+    pub fn fetch_puzzle(&self, year: u16, day: u8) -> Result<String, String> {
+        check_unlocked(year, day, SystemTime::now())?;
+
+        if let Some(page) = self.read_cache(year, day, "puzzle.html") {
+            return Ok(page);
+        }
+
+        let page = self.get(&self.puzzle_url(year, day))?;
+        if crate::puzzle::has_part_two(&page) {
+            self.write_cache(year, day, "puzzle.html", &page);
+        }
+        Ok(page)
+    }
+
+    /// Posts `answer` for `part` of `day` in `year`, returning the response page.
+    /// See [`crate::submit::parse_response`] to read the verdict.
+    pub fn submit_answer(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+    ) -> Result<String, String> {
+        check_unlocked(year, day, SystemTime::now())?;
+
+        let url = self.answer_url(year, day);
+        self.wait_for_throttle();
+        let response = self
+            .agent
+            .post(&url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", &self.user_agent)
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+        Self::read(&url, response)
+    }
+
+    fn get(&self, url: &str) -> Result<String, String> {
+        self.wait_for_throttle();
+        let response = self
+            .agent
+            .get(url)
+            .set("Cookie", &format!("session={}", self.session))
+            .set("User-Agent", &self.user_agent)
+            .call();
+        Self::read(url, response)
+    }
+
+    fn read(url: &str, response: Result<ureq::Response, ureq::Error>) -> Result<String, String> {
+        match response {
+            Ok(response) => response.into_string().map_err(|e| e.to_string()),
+            Err(ureq::Error::Status(404, _)) => Err(format!(
+                "\"{}\" was not found. Is the puzzle unlocked yet?",
+                url
+            )),
+            Err(ureq::Error::Status(400 | 500, _)) => Err(format!(
+                "\"{}\" was rejected. Your session cookie might have expired.",
+                url
+            )),
+            Err(ureq::Error::Status(status, _)) => {
+                Err(format!("\"{}\" returned status {}", url, status))
+            }
+            Err(e) => Err(e.to_string()),
+        }
+    }
+
+    /// Sleeps until `throttle` has passed since the last request, which is shared through the cache.
+    fn wait_for_throttle(&self) {
+        let last_request = self.last_request.get().or_else(|| {
+            let millis = fs::read_to_string(self.cache_dir.as_ref()?.join("last_request")).ok()?;
+            Some(UNIX_EPOCH + Duration::from_millis(millis.trim().parse().ok()?))
+        });
+
+        if let Some(elapsed) = last_request.and_then(|last| last.elapsed().ok()) {
+            if elapsed < self.throttle {
+                thread::sleep(self.throttle - elapsed);
+            }
+        }
+
+        let now = SystemTime::now();
+        self.last_request.set(Some(now));
+        if let (Some(dir), Ok(since_epoch)) = (&self.cache_dir, now.duration_since(UNIX_EPOCH)) {
+            let _ = fs::create_dir_all(dir).and_then(|_| {
+                fs::write(
+                    dir.join("last_request"),
+                    since_epoch.as_millis().to_string(),
+                )
+            });
+        }
+    }
+
+    fn cache_path(&self, year: u16, day: u8, name: &str) -> Option<PathBuf> {
+        let dir = self.cache_dir.as_ref()?;
+        Some(
+            dir.join(year.to_string())
+                .join(format!("{:02}", day))
+                .join(name),
+        )
+    }
+
+    fn read_cache(&self, year: u16, day: u8, name: &str) -> Option<String> {
+        fs::read_to_string(self.cache_path(year, day, name)?).ok()
+    }
+
+    /// Caching is best effort; failing to write only means downloading again next time.
+    fn write_cache(&self, year: u16, day: u8, name: &str, contents: &str) {
+        if let Some(path) = self.cache_path(year, day, name) {
+            let _ = path
+                .parent()
+                .map_or(Ok(()), fs::create_dir_all)
+                .and_then(|_| fs::write(path, contents));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader, Write};
+    use std::net::TcpListener;
+    use std::thread;
+
+    /// Serves a single request with `status` and `body`, returning the base url and the received request head.
+    fn mock_server(
+        status: &'static str,
+        body: &'static str,
+    ) -> (String, thread::JoinHandle<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+
+        let handle = thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request = String::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line.trim().is_empty() {
+                    break;
+                }
+                request.push_str(&line);
+            }
+            write!(
+                stream,
+                "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            )
+            .unwrap();
+            request
+        });
+
+        (base_url, handle)
+    }
+
+    #[test]
+    fn test_fetch_input() {
+        let (base_url, server) = mock_server("200 OK", "1000\n2000\n");
+        let client = Client::new(&format!("{}/", base_url), "abc123")
+            .with_throttle(Duration::ZERO)
+            .with_user_agent("tests");
+
+        assert_eq!(client.fetch_input(2015, 1), Ok("1000\n2000\n".to_owned()));
+
+        let request = server.join().unwrap();
+        assert!(request.starts_with("GET /2015/day/1/input HTTP/1.1"));
+        assert!(request.contains("session=abc123"));
+        assert!(request.contains("User-Agent: tests"));
+    }
+
+    #[test]
+    fn test_fetch_puzzle() {
+        let (base_url, server) = mock_server("200 OK", "<article></article>");
+        let client = Client::new(&base_url, "abc123").with_throttle(Duration::ZERO);
+
+        assert_eq!(
+            client.fetch_puzzle(2015, 3),
+            Ok("<article></article>".to_owned())
+        );
+        assert!(server
+            .join()
+            .unwrap()
+            .starts_with("GET /2015/day/3 HTTP/1.1"));
+    }
+
+    #[test]
+    fn test_submit_answer() {
+        let (base_url, server) = mock_server("200 OK", "<main>That's the right answer!</main>");
+        let client = Client::new(&base_url, "abc123").with_throttle(Duration::ZERO);
+
+        assert_eq!(
+            client.submit_answer(2015, 1, 2, "45000"),
+            Ok("<main>That's the right answer!</main>".to_owned())
+        );
+        let request = server.join().unwrap();
+        assert!(request.starts_with("POST /2015/day/1/answer HTTP/1.1"));
+        assert!(request.contains("session=abc123"));
+    }
+
+    #[test]
+    fn test_fetch_input_not_found() {
+        let (base_url, server) = mock_server("404 Not Found", "");
+        let client = Client::new(&base_url, "abc123").with_throttle(Duration::ZERO);
+
+        let error = client.fetch_input(2015, 25).unwrap_err();
+        assert!(error.contains("unlocked"), "{}", error);
+        server.join().unwrap();
+    }
+
+    #[test]
+    fn test_fetch_from_cache() {
+        let cache_dir = env::temp_dir().join(format!("aoc-cache-test-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&cache_dir);
+
+        let (base_url, server) = mock_server("200 OK", "<article></article><article></article>");
+        let client = Client::new(&base_url, "abc123")
+            .with_throttle(Duration::ZERO)
+            .with_cache(&cache_dir);
+
+        let page = client.fetch_puzzle(2015, 4).unwrap();
+        server.join().unwrap();
+        // the mock server is gone, so this has to come from the cache.
+        assert_eq!(client.fetch_puzzle(2015, 4), Ok(page));
+
+        fs::remove_dir_all(&cache_dir).unwrap();
+    }
+
+    #[test]
+    fn test_check_unlocked() {
+        let unlock = unlock_time(2022, 1);
+        assert_eq!(
+            unlock.duration_since(UNIX_EPOCH).unwrap().as_secs(),
+            1_669_870_800 // 2022-12-01T05:00:00Z
+        );
+
+        assert_eq!(check_unlocked(2022, 1, unlock), Ok(()));
+        assert_eq!(
+            check_unlocked(2022, 1, unlock - Duration::from_secs(3_661)),
+            Err("day 1 of 2022 unlocks in 1h 1m 1s".to_owned())
+        );
+        assert!(check_unlocked(2022, 26, unlock).is_err());
+    }
+}
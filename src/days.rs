@@ -0,0 +1,7 @@
+//! The solutions, one module per day, grouped by event year: `days::y2022::day07`.
+//! The days of the primary year are also re-exported here, e.g. `days::day07`.
+//! `cargo scaffold` adds new days and years.
+#[path = "y2022/days.rs"]
+pub mod y2022;
+
+pub use y2022::*;
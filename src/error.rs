@@ -0,0 +1,122 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt;
+
+/// Why a solution could not produce an answer for its input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// The input doesn't have the expected format. `line` and `column` are 1-based,
+    /// `snippet` is the offending line.
+    Parse {
+        line: usize,
+        column: usize,
+        message: String,
+        snippet: String,
+    },
+    /// The input parsed, but the solution can't get to an answer with it.
+    Unsolvable(String),
+}
+
+impl Error {
+    /// A parse error at the start of `rest`, which has to be a slice of `input`,
+    /// e.g. a line of it or what a parser left over.
+    pub fn parse_at(input: &str, rest: &str, message: impl Into<String>) -> Self {
+        let offset = (rest.as_ptr() as usize)
+            .checked_sub(input.as_ptr() as usize)
+            .filter(|offset| *offset <= input.len())
+            .unwrap_or(0);
+        let consumed = &input[..offset];
+        let line_start = consumed.rfind('\n').map_or(0, |i| i + 1);
+        let line_end = input[offset..]
+            .find('\n')
+            .map_or(input.len(), |i| offset + i);
+
+        Error::Parse {
+            line: consumed.matches('\n').count() + 1,
+            column: consumed[line_start..].chars().count() + 1,
+            message: message.into(),
+            snippet: input[line_start..line_end]
+                .trim_end_matches('\r')
+                .to_owned(),
+        }
+    }
+
+    /// Converts a failed `nom` parser run on `input`.
+    pub fn from_nom(input: &str, err: nom::Err<nom::error::Error<&str>>) -> Self {
+        match err {
+            nom::Err::Error(e) | nom::Err::Failure(e) => {
+                Error::parse_at(input, e.input, format!("expected {}", e.code.description()))
+            }
+            nom::Err::Incomplete(_) => {
+                Error::parse_at(input, &input[input.len()..], "unexpected end of input")
+            }
+        }
+    }
+
+    pub fn unsolvable(message: impl Into<String>) -> Self {
+        Error::Unsolvable(message.into())
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse {
+                line,
+                column,
+                message,
+                snippet,
+            } => {
+                let gutter = " ".repeat(line.to_string().len());
+                writeln!(
+                    f,
+                    "parse error at line {}, column {}: {}",
+                    line, column, message
+                )?;
+                writeln!(f, "{} | {}", line, snippet)?;
+                write!(f, "{} | {}^", gutter, " ".repeat(column - 1))
+            }
+            Error::Unsolvable(message) => write!(f, "{}", message),
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::character::complete;
+
+    #[test]
+    fn test_parse_at() {
+        let input = "1-2\n3-x\n";
+        let line = input.lines().nth(1).unwrap();
+        assert_eq!(
+            Error::parse_at(input, &line[2..], "not a number").to_string(),
+            "parse error at line 2, column 3: not a number\n2 | 3-x\n  |   ^"
+        );
+        assert_eq!(
+            Error::parse_at(input, input, "oops"),
+            Error::Parse {
+                line: 1,
+                column: 1,
+                message: "oops".to_owned(),
+                snippet: "1-2".to_owned(),
+            }
+        );
+    }
+
+    #[test]
+    fn test_from_nom() {
+        let input = "12\nab";
+        let (rest, _) = complete::u32::<_, nom::error::Error<&str>>(input).unwrap();
+        let err = complete::u32::<_, nom::error::Error<&str>>(&rest[1..]).unwrap_err();
+        assert_eq!(
+            Error::from_nom(input, err).to_string(),
+            "parse error at line 2, column 1: expected Digit\n2 | ab\n  | ^"
+        );
+    }
+}
@@ -2,3 +2,7 @@
  * Use this file if you want to extract helpers from your solutions.
  * Example import from this file: `use advent_of_code::helpers::example_fn;`.
  */
+pub mod grid;
+pub mod parse;
+pub mod ranges;
+pub mod search;
@@ -0,0 +1,531 @@
+//! Points, directions and grids for 2D puzzles.
+//!
+//! `y` grows downwards, like the rows of a puzzle input: `Direction::Up` is `y - 1`.
+use std::collections::HashMap;
+use std::fmt;
+use std::hash::Hash;
+use std::ops::{Add, AddAssign, Index, IndexMut, Sub};
+
+use crate::Error;
+
+/// Integer types that can be used as coordinates of a [`Point`].
+pub trait Coord: Copy + Ord + Hash + fmt::Debug + Add<Output = Self> + Sub<Output = Self> {
+    /// `|self - other|`, which doesn't underflow for unsigned types.
+    fn distance(self, other: Self) -> Self;
+    /// `self + delta`, or `None` if that doesn't fit the type.
+    fn checked_offset(self, delta: i8) -> Option<Self>;
+}
+
+macro_rules! impl_coord {
+    (signed: $($s:ty),*; unsigned: $($u:ty),*) => {
+        $(impl Coord for $s {
+            fn distance(self, other: Self) -> Self {
+                (self - other).abs()
+            }
+            fn checked_offset(self, delta: i8) -> Option<Self> {
+                self.checked_add(delta.into())
+            }
+        })*
+        $(impl Coord for $u {
+            fn distance(self, other: Self) -> Self {
+                self.abs_diff(other)
+            }
+            fn checked_offset(self, delta: i8) -> Option<Self> {
+                self.checked_add_signed(delta.into())
+            }
+        })*
+    };
+}
+
+impl_coord!(signed: i16, i32, i64, isize; unsigned: u16, u32, u64, usize);
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+}
+
+impl<T: Coord> Point<T> {
+    pub fn manhattan(self, other: Self) -> T {
+        self.x.distance(other.x) + self.y.distance(other.y)
+    }
+
+    pub fn chebyshev(self, other: Self) -> T {
+        self.x.distance(other.x).max(self.y.distance(other.y))
+    }
+
+    /// The neighbouring point in direction `dir`, or `None` if it doesn't fit the coordinate type.
+    pub fn step(self, dir: impl Into<Direction8>) -> Option<Self> {
+        let (dx, dy) = dir.into().delta();
+        Some(Point::new(
+            self.x.checked_offset(dx)?,
+            self.y.checked_offset(dy)?,
+        ))
+    }
+
+    /// The up to 4 orthogonal neighbours.
+    pub fn neighbours4(self) -> impl Iterator<Item = Self> {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(dir))
+    }
+
+    /// The up to 8 orthogonal and diagonal neighbours.
+    pub fn neighbours8(self) -> impl Iterator<Item = Self> {
+        Direction8::ALL
+            .into_iter()
+            .filter_map(move |dir| self.step(dir))
+    }
+}
+
+impl<T: Coord> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Coord> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: Coord> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+/// Moves one step. Panics if the point leaves its coordinate type, like integer overflow does.
+impl<T: Coord, D: Into<Direction8>> Add<D> for Point<T> {
+    type Output = Self;
+
+    fn add(self, dir: D) -> Self {
+        let dir = dir.into();
+        self.step(dir)
+            .unwrap_or_else(|| panic!("moving {:?} from {:?} overflows", dir, self))
+    }
+}
+
+impl<T: Coord, D: Into<Direction8>> AddAssign<D> for Point<T> {
+    fn add_assign(&mut self, dir: D) {
+        *self = *self + dir;
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Direction {
+    /// Clockwise, starting with `Up`.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// Reads `U`/`R`/`D`/`L` or `^`/`>`/`v`/`<`.
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            'U' | '^' => Some(Direction::Up),
+            'R' | '>' => Some(Direction::Right),
+            'D' | 'v' => Some(Direction::Down),
+            'L' | '<' => Some(Direction::Left),
+            _ => None,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+}
+
+/// Orthogonal and diagonal directions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Direction8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Direction8 {
+    /// Clockwise, starting with `Up`.
+    pub const ALL: [Direction8; 8] = [
+        Direction8::Up,
+        Direction8::UpRight,
+        Direction8::Right,
+        Direction8::DownRight,
+        Direction8::Down,
+        Direction8::DownLeft,
+        Direction8::Left,
+        Direction8::UpLeft,
+    ];
+
+    /// `(dx, dy)` of one step in this direction.
+    pub fn delta(self) -> (i8, i8) {
+        match self {
+            Direction8::Up => (0, -1),
+            Direction8::UpRight => (1, -1),
+            Direction8::Right => (1, 0),
+            Direction8::DownRight => (1, 1),
+            Direction8::Down => (0, 1),
+            Direction8::DownLeft => (-1, 1),
+            Direction8::Left => (-1, 0),
+            Direction8::UpLeft => (-1, -1),
+        }
+    }
+}
+
+impl From<Direction> for Direction8 {
+    fn from(dir: Direction) -> Self {
+        match dir {
+            Direction::Up => Direction8::Up,
+            Direction::Right => Direction8::Right,
+            Direction::Down => Direction8::Down,
+            Direction::Left => Direction8::Left,
+        }
+    }
+}
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Builds a grid from rows of cells. Returns `None` if the rows differ in length.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let width = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != width) {
+            return None;
+        }
+        Some(Grid {
+            width,
+            height: rows.len(),
+            cells: rows.into_iter().flatten().collect(),
+        })
+    }
+
+    /// Parses one row per line, converting each character with `cell`.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> Option<T>) -> Result<Self, Error> {
+        let mut cells = Vec::new();
+        let mut width = None;
+        let mut height = 0;
+
+        for line in input.trim_end_matches(['\r', '\n']).lines() {
+            for (i, c) in line.char_indices() {
+                let value = cell(c).ok_or_else(|| {
+                    Error::parse_at(input, &line[i..], format!("unexpected {:?}", c))
+                })?;
+                cells.push(value);
+            }
+            let row_width = line.chars().count();
+            match width {
+                Some(width) if width != row_width => {
+                    return Err(Error::parse_at(
+                        input,
+                        line,
+                        format!("expected a row of {} cells", width),
+                    ))
+                }
+                _ => width = Some(row_width),
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, p: Point<usize>) -> bool {
+        p.x < self.width && p.y < self.height
+    }
+
+    pub fn get(&self, p: Point<usize>) -> Option<&T> {
+        self.contains(p)
+            .then(|| &self.cells[p.y * self.width + p.x])
+    }
+
+    pub fn get_mut(&mut self, p: Point<usize>) -> Option<&mut T> {
+        if self.contains(p) {
+            Some(&mut self.cells[p.y * self.width + p.x])
+        } else {
+            None
+        }
+    }
+
+    /// Every point of the grid, row by row.
+    pub fn points(&self) -> impl Iterator<Item = Point<usize>> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell with its point, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point<usize>, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        self.cells.chunks(self.width.max(1))
+    }
+
+    /// The first point, row by row, whose cell matches `predicate`.
+    pub fn position(&self, predicate: impl Fn(&T) -> bool) -> Option<Point<usize>> {
+        self.iter()
+            .find(|(_, cell)| predicate(cell))
+            .map(|(p, _)| p)
+    }
+
+    /// The orthogonal neighbours of `p` that are inside the grid.
+    pub fn neighbours4(&self, p: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        p.neighbours4().filter(|n| self.contains(*n))
+    }
+
+    /// The orthogonal and diagonal neighbours of `p` that are inside the grid.
+    pub fn neighbours8(&self, p: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        p.neighbours8().filter(|n| self.contains(*n))
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, p: Point<usize>) -> &T {
+        let (width, height) = (self.width, self.height);
+        self.get(p)
+            .unwrap_or_else(|| panic!("{} is outside the {}x{} grid", p, width, height))
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, p: Point<usize>) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(p)
+            .unwrap_or_else(|| panic!("{} is outside the {}x{} grid", p, width, height))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// A grid without fixed bounds that only stores the cells that were set.
+#[derive(Debug, Clone)]
+pub struct SparseGrid<T, C = i32> {
+    cells: HashMap<Point<C>, T>,
+}
+
+impl<T, C: Coord> SparseGrid<T, C> {
+    pub fn new() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+        }
+    }
+
+    pub fn insert(&mut self, p: Point<C>, value: T) -> Option<T> {
+        self.cells.insert(p, value)
+    }
+
+    pub fn remove(&mut self, p: Point<C>) -> Option<T> {
+        self.cells.remove(&p)
+    }
+
+    pub fn get(&self, p: Point<C>) -> Option<&T> {
+        self.cells.get(&p)
+    }
+
+    pub fn get_mut(&mut self, p: Point<C>) -> Option<&mut T> {
+        self.cells.get_mut(&p)
+    }
+
+    pub fn contains(&self, p: Point<C>) -> bool {
+        self.cells.contains_key(&p)
+    }
+
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Point<C>, &T)> {
+        self.cells.iter().map(|(p, value)| (*p, value))
+    }
+
+    /// The smallest and largest corner of the rectangle around every set cell.
+    pub fn bounds(&self) -> Option<(Point<C>, Point<C>)> {
+        let mut points = self.cells.keys();
+        let first = *points.next()?;
+        Some(points.fold((first, first), |(min, max), p| {
+            (
+                Point::new(min.x.min(p.x), min.y.min(p.y)),
+                Point::new(max.x.max(p.x), max.y.max(p.y)),
+            )
+        }))
+    }
+}
+
+impl<T, C: Coord> Default for SparseGrid<T, C> {
+    fn default() -> Self {
+        SparseGrid::new()
+    }
+}
+
+impl<T, C: Coord> FromIterator<(Point<C>, T)> for SparseGrid<T, C> {
+    fn from_iter<I: IntoIterator<Item = (Point<C>, T)>>(iter: I) -> Self {
+        SparseGrid {
+            cells: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl<T, C: Coord> Extend<(Point<C>, T)> for SparseGrid<T, C> {
+    fn extend<I: IntoIterator<Item = (Point<C>, T)>>(&mut self, iter: I) {
+        self.cells.extend(iter);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_point() {
+        let a = Point::new(1, -2);
+        let b = Point::new(-3, 4);
+        assert_eq!(a.manhattan(b), 10);
+        assert_eq!(a.chebyshev(b), 6);
+        assert_eq!(a + b, Point::new(-2, 2));
+        assert_eq!(a + Direction::Up, Point::new(1, -3));
+        assert_eq!(a + Direction8::DownLeft, Point::new(0, -1));
+        assert_eq!(Point::new(3u32, 1).manhattan(Point::new(1, 4)), 5);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        assert_eq!(Point::new(0, 0).neighbours4().count(), 4);
+        assert_eq!(Point::new(0, 0).neighbours8().count(), 8);
+        assert_eq!(
+            Point::new(0usize, 1).neighbours4().collect::<Vec<_>>(),
+            vec![Point::new(0, 0), Point::new(1, 1), Point::new(0, 2)]
+        );
+    }
+
+    #[test]
+    fn test_direction() {
+        assert_eq!(Direction::from_char('v'), Some(Direction::Down));
+        assert_eq!(Direction::Left.turn_right(), Direction::Up);
+        assert_eq!(Direction::Up.turn_left(), Direction::Left);
+        assert_eq!(Direction::Right.opposite(), Direction::Left);
+    }
+
+    #[test]
+    fn test_grid() {
+        let grid = Grid::parse("#..\n.#.\n", |c| Some(c == '#')).unwrap();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert!(grid[Point::new(1, 1)]);
+        assert_eq!(grid.get(Point::new(3, 0)), None);
+        assert_eq!(grid.neighbours8(Point::new(0, 0)).count(), 3);
+        assert_eq!(grid.position(|c| *c), Some(Point::new(0, 0)));
+        assert_eq!(
+            Grid::from_rows(vec![vec![true, false, false], vec![false, true, false]]),
+            Some(grid)
+        );
+
+        let digits = Grid::parse("12\n34", |c| c.to_digit(10)).unwrap();
+        assert_eq!(digits.to_string(), "12\n34\n");
+    }
+
+    #[test]
+    fn test_grid_parse_error() {
+        let err = Grid::parse("..\n.x\n", |c| (c == '.').then_some(())).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("parse error at line 2, column 2"));
+
+        let err = Grid::parse("..\n...\n", |c| (c == '.').then_some(())).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("parse error at line 2, column 1: expected a row of 2 cells"));
+    }
+
+    #[test]
+    fn test_sparse_grid() {
+        let mut grid: SparseGrid<char> = SparseGrid::new();
+        assert_eq!(grid.bounds(), None);
+        grid.insert(Point::new(2, -1), '#');
+        grid.insert(Point::new(-3, 5), '#');
+        assert!(grid.contains(Point::new(2, -1)));
+        assert_eq!(grid.bounds(), Some((Point::new(-3, -1), Point::new(2, 5))));
+    }
+}
@@ -0,0 +1,189 @@
+//! Building blocks for parsing puzzle inputs with `nom`.
+use std::str::FromStr;
+
+use nom::{
+    bytes::complete::tag,
+    character::complete::{digit1, line_ending, one_of},
+    combinator::{map_res, opt, recognize},
+    multi::many1,
+    sequence::{pair, preceded, separated_pair},
+    IResult, Parser,
+};
+
+use crate::Error;
+
+/// Runs `parser` on the whole of `input`. Anything but trailing whitespace left over is an error,
+/// reported at the start of the leftover.
+pub fn parse_all<'a, T>(
+    input: &'a str,
+    mut parser: impl Parser<&'a str, T, nom::error::Error<&'a str>>,
+) -> Result<T, Error> {
+    let (rest, value) = parser.parse(input).map_err(|e| Error::from_nom(input, e))?;
+    if rest.trim_end().is_empty() {
+        Ok(value)
+    } else {
+        Err(Error::parse_at(
+            input,
+            rest.trim_start(),
+            "unexpected input",
+        ))
+    }
+}
+
+/// An integer with an optional sign, e.g. `-12` or `+3`.
+pub fn signed<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(recognize(pair(opt(one_of("+-")), digit1)), str::parse)(input)
+}
+
+/// An integer without a sign.
+pub fn unsigned<T: FromStr>(input: &str) -> IResult<&str, T> {
+    map_res(digit1, str::parse)(input)
+}
+
+/// A point written as `x=2, y=-18`.
+pub fn point<T: FromStr>(input: &str) -> IResult<&str, (T, T)> {
+    separated_pair(
+        preceded(tag("x="), signed),
+        tag(", "),
+        preceded(tag("y="), signed),
+    )(input)
+}
+
+/// One or more `item`s separated by `separator`, like `separated_list1`. The list ends at the
+/// end of the input or before an empty line. Anywhere else an `item` that doesn't parse fails
+/// the whole parser, so the error points into that item instead of after the last good one.
+fn separated_items<'a, T, S>(
+    mut separator: impl Parser<&'a str, S, nom::error::Error<&'a str>>,
+    mut item: impl Parser<&'a str, T, nom::error::Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    move |input| {
+        let (mut rest, first) = item.parse(input)?;
+        let mut items = vec![first];
+        loop {
+            let next = match separator.parse(rest) {
+                Ok((next, _)) => next,
+                Err(nom::Err::Error(_)) => return Ok((rest, items)),
+                Err(e) => return Err(e),
+            };
+            if next.trim_end().is_empty() || line_ending::<_, ()>(next).is_ok() {
+                return Ok((rest, items));
+            }
+            let (next, value) = item.parse(next).map_err(|e| match e {
+                nom::Err::Error(e) => nom::Err::Failure(e),
+                e => e,
+            })?;
+            items.push(value);
+            rest = next;
+        }
+    }
+}
+
+/// One or more `item`s, one per line.
+pub fn lines<'a, T>(
+    item: impl Parser<&'a str, T, nom::error::Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    separated_items(line_ending, item)
+}
+
+/// One or more `block`s separated by an empty line.
+pub fn blocks<'a, T>(
+    block: impl Parser<&'a str, T, nom::error::Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<T>> {
+    separated_items(pair(line_ending, line_ending), block)
+}
+
+/// Rows of `cell`s, one row per line. Rows don't have to be the same length.
+pub fn grid<'a, T>(
+    cell: impl Parser<&'a str, T, nom::error::Error<&'a str>>,
+) -> impl FnMut(&'a str) -> IResult<&'a str, Vec<Vec<T>>> {
+    separated_items(line_ending, many1(cell))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use nom::{character::complete::char, combinator::map};
+
+    #[test]
+    fn test_parse_all() {
+        assert_eq!(
+            parse_all("1\n2\n3\n", lines(unsigned::<u32>)),
+            Ok(vec![1, 2, 3])
+        );
+
+        let err = parse_all("1\n2\nx\n", lines(unsigned::<u32>)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 3, column 1: expected Digit\n3 | x\n  | ^"
+        );
+
+        let err = parse_all("x=1, y=2\nx=3, y=z", lines(point::<i32>)).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 2, column 8: expected Digit\n2 | x=3, y=z\n  |        ^"
+        );
+
+        let err = parse_all("1 2\n", unsigned::<u32>).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 1, column 3: unexpected input\n1 | 1 2\n  |   ^"
+        );
+    }
+
+    #[test]
+    fn test_parse_all_sections() {
+        // A header line, an empty line, then one `a -> b` per line.
+        let edge = || separated_pair(unsigned::<u32>, tag(" -> "), unsigned::<u32>);
+        let sections = || separated_pair(lines(unsigned::<u32>), tag("\n\n"), lines(edge()));
+
+        assert_eq!(
+            parse_all("1\n2\n\n1 -> 2\n2 -> 1\n", sections()),
+            Ok((vec![1, 2], vec![(1, 2), (2, 1)]))
+        );
+
+        let err = parse_all("1\n2\n\n1 -> 2\n2 => 1\n", sections()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 5, column 2: expected Tag\n5 | 2 => 1\n  |  ^"
+        );
+
+        let err = parse_all("1\n2\n\n1 -> 2\n\n2 -> 1\n", sections()).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "parse error at line 6, column 1: unexpected input\n6 | 2 -> 1\n  | ^"
+        );
+    }
+
+    #[test]
+    fn test_signed() {
+        assert_eq!(signed::<i32>("-12,"), Ok((",", -12)));
+        assert_eq!(signed::<i64>("+3"), Ok(("", 3)));
+        assert_eq!(signed::<i8>("7"), Ok(("", 7)));
+        assert!(signed::<i8>("300").is_err());
+        assert!(unsigned::<u32>("-1").is_err());
+    }
+
+    #[test]
+    fn test_point() {
+        assert_eq!(point::<i32>("x=-2, y=15:"), Ok((":", (-2, 15))));
+    }
+
+    #[test]
+    fn test_blocks() {
+        assert_eq!(
+            parse_all("1\n2\n\n3\n", blocks(lines(unsigned::<u32>))),
+            Ok(vec![vec![1, 2], vec![3]])
+        );
+    }
+
+    #[test]
+    fn test_grid() {
+        let cell = map(one_of(".#"), |c| c == '#');
+        assert_eq!(
+            parse_all("#.\n.#\n", grid(cell)),
+            Ok(vec![vec![true, false], vec![false, true]])
+        );
+        assert!(parse_all("#.\n.x\n", grid(char('#'))).is_err());
+        assert!(parse_all("#.\nx#\n", grid(one_of(".#"))).is_err());
+    }
+}
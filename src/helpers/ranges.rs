@@ -0,0 +1,219 @@
+//! Sets of integers stored as sorted, merged inclusive ranges, and checks on single ranges.
+use std::ops::RangeInclusive;
+
+/// Whether all of `inner` lies within `outer`.
+pub fn contains_range<T: PartialOrd>(outer: &RangeInclusive<T>, inner: &RangeInclusive<T>) -> bool {
+    outer.start() <= inner.start() && inner.end() <= outer.end()
+}
+
+/// Whether `a` and `b` have at least one value in common.
+pub fn overlaps<T: PartialOrd>(a: &RangeInclusive<T>, b: &RangeInclusive<T>) -> bool {
+    a.start() <= b.end() && b.start() <= a.end() && !a.is_empty() && !b.is_empty()
+}
+
+/// A set of integers kept as disjoint ranges in ascending order.
+/// Touching ranges like `1..=2` and `3..=4` are merged into `1..=4`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct RangeSet {
+    ranges: Vec<RangeInclusive<i64>>,
+}
+
+impl RangeSet {
+    pub fn new() -> Self {
+        RangeSet { ranges: Vec::new() }
+    }
+
+    /// The disjoint ranges in ascending order.
+    pub fn ranges(&self) -> &[RangeInclusive<i64>] {
+        &self.ranges
+    }
+
+    pub fn insert(&mut self, range: RangeInclusive<i64>) {
+        let (mut start, mut end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        let first = self
+            .ranges
+            .partition_point(|r| r.end().saturating_add(1) < start);
+        let last =
+            first + self.ranges[first..].partition_point(|r| *r.start() <= end.saturating_add(1));
+        if first < last {
+            start = start.min(*self.ranges[first].start());
+            end = end.max(*self.ranges[last - 1].end());
+        }
+        self.ranges
+            .splice(first..last, std::iter::once(start..=end));
+    }
+
+    pub fn remove(&mut self, range: RangeInclusive<i64>) {
+        let (start, end) = range.into_inner();
+        if start > end {
+            return;
+        }
+        let first = self.ranges.partition_point(|r| *r.end() < start);
+        let last = first + self.ranges[first..].partition_point(|r| *r.start() <= end);
+        if first == last {
+            return;
+        }
+
+        let mut kept = Vec::new();
+        let (lowest, highest) = (*self.ranges[first].start(), *self.ranges[last - 1].end());
+        if lowest < start {
+            kept.push(lowest..=start - 1);
+        }
+        if highest > end {
+            kept.push(end + 1..=highest);
+        }
+        self.ranges.splice(first..last, kept);
+    }
+
+    /// Every integer in either set.
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        let mut union = self.clone();
+        for range in &other.ranges {
+            union.insert(range.clone());
+        }
+        union
+    }
+
+    /// Every integer in `self` but not in `other`.
+    pub fn subtract(&self, other: &RangeSet) -> RangeSet {
+        let mut difference = self.clone();
+        for range in &other.ranges {
+            difference.remove(range.clone());
+        }
+        difference
+    }
+
+    /// The parts of `within` that are not in the set.
+    pub fn gaps(&self, within: RangeInclusive<i64>) -> RangeSet {
+        RangeSet::from(within).subtract(self)
+    }
+
+    /// How many integers are in the set.
+    pub fn len(&self) -> u64 {
+        self.ranges
+            .iter()
+            .map(|r| r.end().abs_diff(*r.start()) + 1)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        self.covers(value..=value)
+    }
+
+    /// Whether all of `range` is in the set.
+    pub fn covers(&self, range: RangeInclusive<i64>) -> bool {
+        let index = self.ranges.partition_point(|r| r.end() < range.start());
+        self.ranges
+            .get(index)
+            .is_some_and(|r| r.start() <= range.start() && range.end() <= r.end())
+    }
+
+    /// Whether any of `range` is in the set.
+    pub fn intersects(&self, range: RangeInclusive<i64>) -> bool {
+        let index = self.ranges.partition_point(|r| r.end() < range.start());
+        self.ranges
+            .get(index)
+            .is_some_and(|r| r.start() <= range.end() && !range.is_empty())
+    }
+}
+
+impl From<RangeInclusive<i64>> for RangeSet {
+    fn from(range: RangeInclusive<i64>) -> Self {
+        let mut set = RangeSet::new();
+        set.insert(range);
+        set
+    }
+}
+
+impl FromIterator<RangeInclusive<i64>> for RangeSet {
+    /// Sorts the ranges once instead of inserting them one by one.
+    fn from_iter<I: IntoIterator<Item = RangeInclusive<i64>>>(iter: I) -> Self {
+        let mut sorted: Vec<RangeInclusive<i64>> =
+            iter.into_iter().filter(|r| !r.is_empty()).collect();
+        sorted.sort_unstable_by_key(|r| *r.start());
+
+        let mut ranges: Vec<RangeInclusive<i64>> = Vec::with_capacity(sorted.len());
+        for range in sorted {
+            match ranges.last_mut() {
+                Some(last) if last.end().saturating_add(1) >= *range.start() => {
+                    *last = *last.start()..=*last.end().max(range.end());
+                }
+                _ => ranges.push(range),
+            }
+        }
+        RangeSet { ranges }
+    }
+}
+
+impl Extend<RangeInclusive<i64>> for RangeSet {
+    fn extend<I: IntoIterator<Item = RangeInclusive<i64>>>(&mut self, iter: I) {
+        for range in iter {
+            self.insert(range);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_insert() {
+        let set = RangeSet::from_iter([5..=7, 1..=2, 3..=3, 10..=12, 11..=20]);
+        assert_eq!(set.ranges(), &[1..=3, 5..=7, 10..=20]);
+        assert_eq!(set.len(), 3 + 3 + 11);
+
+        let mut set = set;
+        set.insert(0..=10);
+        assert_eq!(set, RangeSet::from(0..=20));
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut set = RangeSet::from_iter([1..=10, 20..=30]);
+        set.remove(5..=22);
+        assert_eq!(set.ranges(), &[1..=4, 23..=30]);
+        set.remove(12..=15);
+        assert_eq!(set.ranges(), &[1..=4, 23..=30]);
+        set.remove(1..=1);
+        assert_eq!(set.ranges(), &[2..=4, 23..=30]);
+    }
+
+    #[test]
+    fn test_union_and_subtract() {
+        let a = RangeSet::from_iter([1..=5, 10..=15]);
+        let b = RangeSet::from(4..=11);
+        assert_eq!(a.union(&b), RangeSet::from(1..=15));
+        assert_eq!(a.subtract(&b).ranges(), &[1..=3, 12..=15]);
+        assert_eq!(a.gaps(0..=20).ranges(), &[0..=0, 6..=9, 16..=20]);
+    }
+
+    #[test]
+    fn test_queries() {
+        let set = RangeSet::from_iter([-3..=3, 10..=15]);
+        assert!(set.contains(-3));
+        assert!(!set.contains(4));
+        assert!(set.covers(11..=15));
+        assert!(!set.covers(2..=10));
+        assert!(set.intersects(2..=10));
+        assert!(!set.intersects(4..=9));
+        assert!(RangeSet::new().is_empty());
+    }
+
+    #[test]
+    fn test_single_ranges() {
+        assert!(contains_range(&(2..=8), &(3..=7)));
+        assert!(contains_range(&(3..=3), &(3..=3)));
+        assert!(!contains_range(&(3..=7), &(2..=8)));
+        assert!(overlaps(&(5..=7), &(7..=9)));
+        assert!(overlaps(&(2..=8), &(3..=7)));
+        assert!(!overlaps(&(1..=2), &(3..=4)));
+    }
+}
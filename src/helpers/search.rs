@@ -0,0 +1,302 @@
+//! Shortest paths through graphs given by a neighbour function.
+//!
+//! Nodes are any `Clone + Eq + Hash` state, e.g. a `grid::Point` or a `(Point, Direction)` pair;
+//! every node is expanded at most once per improvement of its cost.
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A path from the start to a goal, both included.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub nodes: Vec<N>,
+    pub cost: C,
+}
+
+/// Costs of edges and paths. `Default` has to be zero.
+pub trait Cost: Copy + Ord + Default + Add<Output = Self> {}
+
+impl<C: Copy + Ord + Default + Add<Output = C>> Cost for C {}
+
+/// Search settings. The free functions [`bfs`], [`dijkstra`] and [`astar`] search without a limit.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Search<C> {
+    max_cost: Option<C>,
+}
+
+impl<C: Cost> Search<C> {
+    pub fn new() -> Self {
+        Search { max_cost: None }
+    }
+
+    /// Gives up on paths that cost more than `max_cost`. For `bfs`, the cost is the number of steps.
+    pub fn with_max_cost(mut self, max_cost: C) -> Self {
+        self.max_cost = Some(max_cost);
+        self
+    }
+
+    fn too_expensive(&self, cost: C) -> bool {
+        self.max_cost.is_some_and(|max| cost > max)
+    }
+
+    /// Dijkstra's algorithm. `neighbours` yields each neighbour with the cost of getting there.
+    pub fn dijkstra<N, I>(
+        &self,
+        start: N,
+        neighbours: impl FnMut(&N) -> I,
+        is_goal: impl FnMut(&N) -> bool,
+    ) -> Option<Path<N, C>>
+    where
+        N: Clone + Eq + Hash,
+        I: IntoIterator<Item = (N, C)>,
+    {
+        self.astar(start, neighbours, |_| C::default(), is_goal)
+    }
+
+    /// A* search. `heuristic` must never overestimate the remaining cost to a goal,
+    /// or the path found might not be the cheapest.
+    pub fn astar<N, I>(
+        &self,
+        start: N,
+        mut neighbours: impl FnMut(&N) -> I,
+        mut heuristic: impl FnMut(&N) -> C,
+        mut is_goal: impl FnMut(&N) -> bool,
+    ) -> Option<Path<N, C>>
+    where
+        N: Clone + Eq + Hash,
+        I: IntoIterator<Item = (N, C)>,
+    {
+        let mut visited = Visited::new(start.clone());
+        let mut best: HashMap<N, (usize, C)> = HashMap::from([(start.clone(), (0, C::default()))]);
+        let mut queue = BinaryHeap::from([Reverse((heuristic(&start), C::default(), 0))]);
+
+        while let Some(Reverse((_, cost, index))) = queue.pop() {
+            let node = visited.nodes[index].0.clone();
+            if best
+                .get(&node)
+                .is_some_and(|(best_index, _)| *best_index != index)
+            {
+                // A cheaper way to this node was found after this one was queued.
+                continue;
+            }
+            if is_goal(&node) {
+                return Some(visited.path(index, cost));
+            }
+
+            for (next, step) in neighbours(&node) {
+                let next_cost = cost + step;
+                if self.too_expensive(next_cost)
+                    || best.get(&next).is_some_and(|(_, c)| *c <= next_cost)
+                {
+                    continue;
+                }
+                let priority = next_cost + heuristic(&next);
+                let next_index = visited.push(next.clone(), index);
+                best.insert(next, (next_index, next_cost));
+                queue.push(Reverse((priority, next_cost, next_index)));
+            }
+        }
+
+        None
+    }
+}
+
+impl Search<usize> {
+    /// Breadth-first search, for graphs where every step costs the same.
+    pub fn bfs<N, I>(
+        &self,
+        start: N,
+        mut neighbours: impl FnMut(&N) -> I,
+        mut is_goal: impl FnMut(&N) -> bool,
+    ) -> Option<Path<N, usize>>
+    where
+        N: Clone + Eq + Hash,
+        I: IntoIterator<Item = N>,
+    {
+        let mut visited = Visited::new(start.clone());
+        let mut seen = HashSet::from([start]);
+        let mut queue = VecDeque::from([(0, 0)]);
+
+        while let Some((index, steps)) = queue.pop_front() {
+            let node = visited.nodes[index].0.clone();
+            if is_goal(&node) {
+                return Some(visited.path(index, steps));
+            }
+            if self.too_expensive(steps + 1) {
+                continue;
+            }
+            for next in neighbours(&node) {
+                if seen.insert(next.clone()) {
+                    queue.push_back((visited.push(next, index), steps + 1));
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Every node reachable from `start`, with the number of steps to get there.
+    pub fn reachable<N, I>(
+        &self,
+        start: N,
+        mut neighbours: impl FnMut(&N) -> I,
+    ) -> HashMap<N, usize>
+    where
+        N: Clone + Eq + Hash,
+        I: IntoIterator<Item = N>,
+    {
+        let mut seen: HashMap<N, usize> = HashMap::from([(start.clone(), 0)]);
+        let mut queue = VecDeque::from([(start, 0)]);
+
+        while let Some((node, steps)) = queue.pop_front() {
+            if self.too_expensive(steps + 1) {
+                continue;
+            }
+            for next in neighbours(&node) {
+                if !seen.contains_key(&next) {
+                    seen.insert(next.clone(), steps + 1);
+                    queue.push_back((next, steps + 1));
+                }
+            }
+        }
+
+        seen
+    }
+}
+
+/// Breadth-first search from `start` to the first node matching `is_goal`.
+pub fn bfs<N, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    Search::new().bfs(start, neighbours, is_goal)
+}
+
+/// Dijkstra's algorithm from `start` to the cheapest node matching `is_goal`.
+pub fn dijkstra<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    Search::new().dijkstra(start, neighbours, is_goal)
+}
+
+/// A* search from `start` to the cheapest node matching `is_goal`.
+pub fn astar<N, C, I>(
+    start: N,
+    neighbours: impl FnMut(&N) -> I,
+    heuristic: impl FnMut(&N) -> C,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Cost,
+    I: IntoIterator<Item = (N, C)>,
+{
+    Search::new().astar(start, neighbours, heuristic, is_goal)
+}
+
+/// Every node put on the queue, with the index of the node it was reached from.
+struct Visited<N> {
+    nodes: Vec<(N, Option<usize>)>,
+}
+
+impl<N: Clone> Visited<N> {
+    fn new(start: N) -> Self {
+        Visited {
+            nodes: vec![(start, None)],
+        }
+    }
+
+    fn push(&mut self, node: N, parent: usize) -> usize {
+        self.nodes.push((node, Some(parent)));
+        self.nodes.len() - 1
+    }
+
+    fn path<C>(&self, mut index: usize, cost: C) -> Path<N, C> {
+        let mut nodes = vec![self.nodes[index].0.clone()];
+        while let Some(parent) = self.nodes[index].1 {
+            nodes.push(self.nodes[parent].0.clone());
+            index = parent;
+        }
+        nodes.reverse();
+        Path { nodes, cost }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::helpers::grid::{Grid, Point};
+
+    // a -1-> b -1-> c -1-> d, plus a shortcut a -5-> d and a dead end b -1-> e.
+    fn edges(node: &char) -> Vec<(char, u32)> {
+        match node {
+            'a' => vec![('b', 1), ('d', 5)],
+            'b' => vec![('c', 1), ('e', 1)],
+            'c' => vec![('d', 1)],
+            _ => vec![],
+        }
+    }
+
+    #[test]
+    fn test_bfs() {
+        let neighbours = |n: &char| edges(n).into_iter().map(|(next, _)| next);
+        let path = bfs('a', neighbours, |n| *n == 'd').unwrap();
+        assert_eq!(path.nodes, vec!['a', 'd']);
+        assert_eq!(path.cost, 1);
+        assert_eq!(bfs('c', neighbours, |n| *n == 'a'), None);
+        assert_eq!(
+            Search::new()
+                .with_max_cost(1)
+                .reachable('a', neighbours)
+                .len(),
+            3
+        );
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        let path = dijkstra('a', edges, |n| *n == 'd').unwrap();
+        assert_eq!(path.nodes, vec!['a', 'b', 'c', 'd']);
+        assert_eq!(path.cost, 3);
+        assert_eq!(
+            Search::new()
+                .with_max_cost(2)
+                .dijkstra('a', edges, |n| *n == 'd'),
+            None
+        );
+    }
+
+    #[test]
+    fn test_astar_on_grid() {
+        let grid = Grid::parse("S..#\n.#.#\n.#..\n...E\n", Some).unwrap();
+        let start = grid.position(|c| *c == 'S').unwrap();
+        let end = grid.position(|c| *c == 'E').unwrap();
+        let neighbours = |p: &Point<usize>| {
+            grid.neighbours4(*p)
+                .filter(|n| grid[*n] != '#')
+                .map(|n| (n, 1))
+                .collect::<Vec<_>>()
+        };
+
+        let path = astar(start, neighbours, |p| p.manhattan(end), |p| *p == end).unwrap();
+        assert_eq!(path.cost, 6);
+        assert_eq!(path.nodes.len(), 7);
+        assert_eq!(
+            dijkstra(start, neighbours, |p| *p == end).map(|p| p.cost),
+            Some(6)
+        );
+    }
+}
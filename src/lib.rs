@@ -3,92 +3,242 @@
  * There is no need to edit this file unless you want to change template functionality.
  * Prefer `./helpers.rs` if you want to extract code from your solutions.
  */
-use std::env;
-use std::fs;
+use std::fmt::Display;
+use std::path::PathBuf;
+use std::time::Instant;
+use std::{env, fs, io};
 
+pub mod answers;
+pub mod args;
+pub mod baseline;
+pub mod bench;
+pub mod client;
+pub mod days;
+pub mod error;
 pub mod helpers;
+pub mod log;
+pub mod puzzle;
+pub mod readme;
+pub mod registry;
+pub mod report;
+pub mod status;
+pub mod submit;
+pub mod template;
+
+// lets the day modules in `days` use `advent_of_code::` paths, like code outside the crate.
+extern crate self as advent_of_code;
+
+pub use error::Error;
+use report::{PartReport, Status};
+
+/// The primary event year of this repository, used when no `--year` is given.
+/// Its day binaries are named `NN`, those of other years `YYYY-NN`.
+pub const YEAR: u16 = 2022;
 
 pub const ANSI_ITALIC: &str = "\x1b[3m";
 pub const ANSI_BOLD: &str = "\x1b[1m";
 pub const ANSI_RESET: &str = "\x1b[0m";
 
+/// Settings of a puzzle that differ between the example and the real input, e.g. which row to scan.
+/// `solve!` and `solution!` call parts that take them with `&EXAMPLE` when run with `--example`
+/// and with `&INPUT` otherwise; tests pass `&EXAMPLE`.
+pub trait Params {
+    /// The settings for the real puzzle input.
+    const INPUT: Self;
+    /// The settings for the example of the puzzle description.
+    const EXAMPLE: Self;
+}
+
+/// The [`Params`] for input read from `source`: `EXAMPLE` for the example, `INPUT` for anything else.
+pub fn params<P: Params>(source: &args::InputSource) -> P {
+    match source {
+        args::InputSource::Example => P::EXAMPLE,
+        _ => P::INPUT,
+    }
+}
+
+/// A day whose input is parsed once and shared by both parts.
+/// `solve!` and `solution!` time the parsing and each part separately.
+/// example: `impl Day for Day07 { type Input<'a> = Vec<Command>; ... }`
+pub trait Day {
+    /// The parsed input. It may borrow from the input text.
+    type Input<'a>;
+    type PartOne: Display;
+    type PartTwo: Display;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Error>;
+    fn part_one(input: &Self::Input<'_>) -> Result<Option<Self::PartOne>, Error>;
+    fn part_two(input: &Self::Input<'_>) -> Result<Option<Self::PartTwo>, Error>;
+}
+
+/// Runs and prints one part of a day, evaluating to its [`PartReport`].
+/// Benchmarks the part instead when the binary was called with `--bench N`,
+/// and evaluates to `None` without running it when another part was selected with `--part`.
+/// A part that takes [`Params`] as second argument is run with the type's `EXAMPLE` for `--example`
+/// and with its `INPUT` otherwise.
+/// A [`Day`] is parsed once and run as a whole, evaluating to the reports of parsing and the parts.
+/// example: `solve!(2022, 7, 1, part_one, input)`, `solve!(2022, 15, 1, part_one, input, Area)`
+/// or `solve!(2022, 13, Day13, input)`
 #[macro_export]
 macro_rules! solve {
-    ($part:expr, $solver:ident, $input:expr) => {{
-        use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-        use std::fmt::Display;
-        use std::time::Instant;
-
-        fn print_result<T: Display>(func: impl FnOnce(&str) -> Option<T>, input: &str) {
-            let timer = Instant::now();
-            let result = func(input);
-            let elapsed = timer.elapsed();
-            match result {
-                Some(result) => {
-                    println!(
-                        "{} {}(elapsed: {:.2?}){}",
-                        result, ANSI_ITALIC, elapsed, ANSI_RESET
-                    );
-                }
-                None => {
-                    println!("not solved.")
-                }
-            }
+    ($year:expr, $day:expr, $part:expr, $solver:ident, $input:expr, $params:ty) => {{
+        let params: $params = advent_of_code::params(&advent_of_code::args::input_source());
+        advent_of_code::solve!(
+            $year,
+            $day,
+            $part,
+            (|input| $solver(input, &params)),
+            $input
+        )
+    }};
+    ($year:expr, $day:expr, $part:expr, $solver:expr, $input:expr) => {{
+        if advent_of_code::args::part().is_some_and(|part| part != $part) {
+            None
+        } else {
+            let report = advent_of_code::bench::run(
+                || advent_of_code::run_part($year, $day, $part, $solver, $input),
+                advent_of_code::bench::iterations_from_args(),
+            );
+            advent_of_code::print_part(&report);
+            Some(report)
         }
-
-        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, $part, ANSI_RESET);
-        print_result($solver, $input);
+    }};
+    ($year:expr, $day:expr, $solver:ty, $input:expr) => {{
+        let parts = advent_of_code::args::part().map_or(vec![1, 2], |part| vec![part]);
+        let mut reports = Vec::new();
+        advent_of_code::registry::run_parsed::<$solver>(
+            $year,
+            $day,
+            $input,
+            &parts,
+            advent_of_code::bench::iterations_from_args(),
+            &mut |report| {
+                advent_of_code::print_part(&report);
+                reports.push(report);
+            },
+        );
+        reports
     }};
 }
 
-pub fn read_file(folder: &str, day: u8) -> String {
-    let cwd = env::current_dir().unwrap();
-
-    let filepath = cwd.join("src").join(folder).join(format!("{:02}.txt", day));
-
-    let f = fs::read_to_string(filepath);
-    f.expect("could not open input file")
+/// Runs a single part against `input`, timing only the solver call itself.
+/// The answer is converted to a `String` after the timer has stopped.
+pub fn run_part<I: ?Sized, T: Display>(
+    year: u16,
+    day: u8,
+    part: u8,
+    func: impl FnOnce(&I) -> Result<Option<T>, Error>,
+    input: &I,
+) -> PartReport {
+    let timer = Instant::now();
+    let result = func(input);
+    let elapsed = timer.elapsed();
+    let (answer, error) = match result {
+        Ok(answer) => (answer.map(|r| r.to_string()), None),
+        Err(e) => (None, Some(e)),
+    };
+    PartReport {
+        year,
+        day,
+        part,
+        answer,
+        error,
+        elapsed,
+        bench: None,
+    }
 }
 
-fn parse_time(val: &str, postfix: &str) -> f64 {
-    val.split(postfix).next().unwrap().parse().unwrap()
+/// Times parsing `input`, returning the parsed input next to a report for part [`report::PARSE`].
+pub fn run_parse<'a, T>(
+    year: u16,
+    day: u8,
+    parse: impl FnOnce(&'a str) -> Result<T, Error>,
+    input: &'a str,
+) -> (Result<T, Error>, PartReport) {
+    let timer = Instant::now();
+    let result = parse(input);
+    let elapsed = timer.elapsed();
+    let report = PartReport {
+        year,
+        day,
+        part: report::PARSE,
+        answer: None,
+        error: result.as_ref().err().cloned(),
+        elapsed,
+        bench: None,
+    };
+    (result, report)
 }
 
-pub fn parse_exec_time(output: &str) -> f64 {
-    output.lines().fold(0_f64, |acc, l| {
-        if !l.contains("elapsed:") {
-            acc
-        } else {
-            let timing = l.split("(elapsed: ").last().unwrap();
-            // use `contains` istd. of `ends_with`: string may contain ANSI escape sequences.
-            // for possible time formats, see: https://github.com/rust-lang/rust/blob/1.64.0/library/core/src/time.rs#L1176-L1200
-            if timing.contains("ns)") {
-                acc // range below rounding precision.
-            } else if timing.contains("µs)") {
-                acc + parse_time(timing, "µs") / 1000_f64
-            } else if timing.contains("ms)") {
-                acc + parse_time(timing, "ms")
-            } else if timing.contains("s)") {
-                acc + parse_time(timing, "s") * 1000_f64
-            } else {
-                acc
-            }
+pub fn print_part(report: &PartReport) {
+    if report.part == report::PARSE {
+        println!("🎄 {}Parse{} 🎄", ANSI_BOLD, ANSI_RESET);
+    } else {
+        println!("🎄 {}Part {}{} 🎄", ANSI_BOLD, report.part, ANSI_RESET);
+    }
+    match (&report.answer, &report.bench) {
+        (Some(answer), Some(b)) => {
+            println!(
+                "{} {}(min: {:.2?}, median: {:.2?}, mean: {:.2?}, p95: {:.2?}, stddev: {:.2?}, runs: {}){}",
+                answer, ANSI_ITALIC, b.min, b.median, b.mean, b.p95, b.stddev, b.runs, ANSI_RESET
+            );
         }
+        (Some(answer), None) => {
+            println!(
+                "{} {}(elapsed: {:.2?}){}",
+                answer, ANSI_ITALIC, report.elapsed, ANSI_RESET
+            );
+        }
+        (None, bench) => match (&report.error, report.status()) {
+            (Some(error), _) => println!("{}error:{} {}", ANSI_BOLD, ANSI_RESET, error),
+            (None, Status::Parsed) => match bench {
+                Some(b) => println!(
+                    "{}(min: {:.2?}, median: {:.2?}, mean: {:.2?}, p95: {:.2?}, stddev: {:.2?}, runs: {}){}",
+                    ANSI_ITALIC, b.min, b.median, b.mean, b.p95, b.stddev, b.runs, ANSI_RESET
+                ),
+                None => println!("{}(elapsed: {:.2?}){}", ANSI_ITALIC, report.elapsed, ANSI_RESET),
+            },
+            (None, _) => println!("not solved."),
+        },
+    }
+}
+
+/// Reads the input a day binary was pointed at with `--input <path>`, `--example` or `-`,
+/// by default `src/yYYYY/inputs/NN.txt`. Exits with a message when it can't be read.
+pub fn read_input(year: u16, day: u8) -> String {
+    let source = args::input_source();
+    source.read(year, day).unwrap_or_else(|e| {
+        eprintln!("Failed to read {} of day {:02}: {}", source, day, e);
+        std::process::exit(1);
     })
 }
 
-/// copied from: https://github.com/rust-lang/rust/blob/1.64.0/library/std/src/macros.rs#L328-L333
-#[cfg(test)]
-macro_rules! assert_approx_eq {
-    ($a:expr, $b:expr) => {{
-        let (a, b) = (&$a, &$b);
-        assert!(
-            (*a - *b).abs() < 1.0e-6,
-            "{} is not approximately equal to {}",
-            *a,
-            *b
-        );
-    }};
+pub fn read_file(year: u16, folder: &str, day: u8) -> String {
+    try_read_file(year, folder, day).expect("could not open input file")
+}
+
+pub fn try_read_file(year: u16, folder: &str, day: u8) -> io::Result<String> {
+    let cwd = env::current_dir()?;
+
+    fs::read_to_string(
+        cwd.join(year_dir(year))
+            .join(folder)
+            .join(format!("{:02}.txt", day)),
+    )
+}
+
+/// Directory holding a year's day binaries (`bin`), `inputs`, `examples` and answers.
+pub fn year_dir(year: u16) -> PathBuf {
+    PathBuf::from("src").join(format!("y{}", year))
+}
+
+/// Name of a day's binary, as used with `cargo solve`.
+pub fn bin_name(year: u16, day: u8) -> String {
+    if year == YEAR {
+        format!("{:02}", day)
+    } else {
+        format!("{}-{:02}", year, day)
+    }
 }
 
 #[cfg(test)]
@@ -96,30 +246,32 @@ mod tests {
     use super::*;
 
     #[test]
-    fn test_parse_exec_time() {
-        assert_approx_eq!(
-            parse_exec_time(&format!(
-                "🎄 Part 1 🎄\n0 (elapsed: 74.13ns){}\n🎄 Part 2 🎄\n0 (elapsed: 50.00ns){}",
-                ANSI_RESET, ANSI_RESET
-            )),
-            0_f64
-        );
+    fn test_run_part() {
+        let report = run_part(2022, 3, 2, |input| Ok(Some(input.len())), "abcd");
+        assert_eq!(report.year, 2022);
+        assert_eq!(report.day, 3);
+        assert_eq!(report.part, 2);
+        assert_eq!(report.answer, Some("4".to_owned()));
+        assert_eq!(report.status(), Status::Solved);
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 755µs)\n🎄 Part 2 🎄\n0 (elapsed: 700µs)"),
-            1.455_f64
-        );
+        let report = run_part(2022, 3, 1, |_| Ok(None::<u32>), "abcd");
+        assert_eq!(report.answer, None);
+        assert_eq!(report.status(), Status::Unsolved);
 
-        assert_approx_eq!(
-            parse_exec_time("🎄 Part 1 🎄\n0 (elapsed: 70µs)\n🎄 Part 2 🎄\n0 (elapsed: 1.45ms)"),
-            1.52_f64
+        let report = run_part(
+            2022,
+            3,
+            1,
+            |input| Err::<Option<u32>, _>(Error::parse_at(input, &input[2..], "expected digit")),
+            "abcd",
         );
+        assert_eq!(report.answer, None);
+        assert_eq!(report.status(), Status::Error);
+    }
 
-        assert_approx_eq!(
-            parse_exec_time(
-                "🎄 Part 1 🎄\n0 (elapsed: 10.3s)\n🎄 Part 2 🎄\n0 (elapsed: 100.50ms)"
-            ),
-            10400.50_f64
-        );
+    #[test]
+    fn test_bin_name() {
+        assert_eq!(bin_name(YEAR, 7), "07");
+        assert_eq!(bin_name(2021, 7), "2021-07");
     }
 }
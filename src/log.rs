@@ -0,0 +1,139 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::env;
+use std::fmt;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+/// Environment variable holding the log level, e.g. `AOC_LOG=trace`.
+pub const LEVEL_ENV: &str = "AOC_LOG";
+
+/// How much of the `info!`, `debug!` and `trace!` output is shown.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Off,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "off" | "" => Ok(Level::Off),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            other => Err(format!(
+                "unknown log level \"{}\", expected off, info, debug or trace",
+                other
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Off => "off",
+            Level::Info => "info",
+            Level::Debug => "debug",
+            Level::Trace => "trace",
+        };
+        write!(f, "{}", name)
+    }
+}
+
+/// The level from `AOC_LOG`, raised to at least `debug` by a `--verbose` argument.
+/// Read once per process.
+pub fn level() -> Level {
+    static LEVEL: OnceLock<Level> = OnceLock::new();
+    *LEVEL.get_or_init(|| {
+        let from_env = env::var(LEVEL_ENV).ok().and_then(|value| {
+            value
+                .parse()
+                .map_err(|e| eprintln!("Ignoring {}: {}", LEVEL_ENV, e))
+                .ok()
+        });
+        resolve(from_env, env::args().any(|arg| arg == "--verbose"))
+    })
+}
+
+fn resolve(from_env: Option<Level>, verbose: bool) -> Level {
+    let level = from_env.unwrap_or(Level::Off);
+    if verbose {
+        level.max(Level::Debug)
+    } else {
+        level
+    }
+}
+
+pub fn enabled(level: Level) -> bool {
+    level <= self::level()
+}
+
+/// Prints one log line to stderr. Use the `info!`, `debug!` and `trace!` macros instead.
+pub fn write(level: Level, target: &str, args: fmt::Arguments) {
+    eprintln!("[{} {}] {}", level, target, args);
+}
+
+/// Logs at `level` when it is enabled. Compiled out of release builds,
+/// so logging never shows up in the timings of optimized runs.
+/// example: `log!(Level::Debug, "{} directories", dirs.len())`
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if cfg!(debug_assertions) && $crate::log::enabled($level) {
+            $crate::log::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+/// example: `advent_of_code::info!("parsed {} sensors", sensors.len())`
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => {
+        $crate::log!($crate::log::Level::Info, $($arg)+)
+    };
+}
+
+/// example: `advent_of_code::debug!("used: {}, free: {}", used, free)`
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => {
+        $crate::log!($crate::log::Level::Debug, $($arg)+)
+    };
+}
+
+/// example: `advent_of_code::trace!("cd {}", dir)`
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => {
+        $crate::log!($crate::log::Level::Trace, $($arg)+)
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_level() {
+        assert_eq!("TRACE".parse(), Ok(Level::Trace));
+        assert_eq!("off".parse(), Ok(Level::Off));
+        assert!("loud".parse::<Level>().is_err());
+    }
+
+    #[test]
+    fn test_resolve() {
+        assert_eq!(resolve(None, false), Level::Off);
+        assert_eq!(resolve(None, true), Level::Debug);
+        assert_eq!(resolve(Some(Level::Info), true), Level::Debug);
+        assert_eq!(resolve(Some(Level::Trace), true), Level::Trace);
+        assert_eq!(resolve(Some(Level::Info), false), Level::Info);
+    }
+}
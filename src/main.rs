@@ -2,45 +2,504 @@
  * This file contains template code.
  * There is no need to edit this file unless you want to change template functionality.
  */
-use advent_of_code::{ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
-use std::process::Command;
+use advent_of_code::answers::{self, Answers, Verdict};
+use advent_of_code::baseline::{self, Baseline, Comparison};
+use advent_of_code::client::Client;
+use advent_of_code::days;
+use advent_of_code::readme;
+use advent_of_code::registry::{self, Solution};
+use advent_of_code::report::{self, Format, PartReport, SkipReason, Skipped};
+use advent_of_code::status::{self, DayFiles, DayStatus};
+use advent_of_code::submit::{self, Outcome, Submissions};
+use advent_of_code::{solution, ANSI_BOLD, ANSI_ITALIC, ANSI_RESET};
+use pico_args::Arguments;
+use std::path::PathBuf;
+use std::{fs, io, process};
 
-fn main() {
-    let total: f64 = (1..=25)
-        .map(|day| {
-            let day = format!("{:02}", day);
-
-            let cmd = Command::new("cargo")
-                .args(["run", "--release", "--bin", &day])
-                .output()
-                .unwrap();
-
-            println!("----------");
-            println!("{}| Day {} |{}", ANSI_BOLD, day, ANSI_RESET);
-            println!("----------");
-
-            let output = String::from_utf8(cmd.stdout).unwrap();
-            let is_empty = output.is_empty();
-
-            println!(
-                "{}",
-                if is_empty {
-                    "Not solved."
-                } else {
-                    output.trim()
+// Every day registered here runs in a single process. `cargo scaffold` adds new days.
+fn solutions() -> Vec<Solution> {
+    vec![
+        solution!(2022, 1, days::y2022::day01),
+        solution!(2022, 2, days::y2022::day02),
+        solution!(2022, 3, days::y2022::day03),
+        solution!(2022, 4, days::y2022::day04),
+        solution!(2022, 5, days::y2022::day05),
+        solution!(2022, 6, days::y2022::day06),
+        solution!(2022, 7, days::y2022::day07::Day07 as Day),
+        solution!(2022, 9, days::y2022::day09),
+        solution!(2022, 10, days::y2022::day10),
+        solution!(2022, 13, days::y2022::day13::Day13 as Day),
+        solution!(2022, 15, days::y2022::day15, Area),
+        solution!(2022, 21, days::y2022::day21::Day21 as Day),
+    ]
+}
+
+struct RunArgs {
+    year: u16,
+    example: bool,
+    format: Format,
+    bench: Option<u32>,
+    save_baseline: bool,
+    compare: bool,
+    baseline: PathBuf,
+    threshold: f64,
+}
+
+fn parse_run_args(mut args: Arguments) -> Result<RunArgs, pico_args::Error> {
+    Ok(RunArgs {
+        year: parse_year(&mut args)?,
+        example: args.contains("--example"),
+        format: args.opt_value_from_str("--format")?.unwrap_or_default(),
+        bench: args.opt_value_from_str("--bench")?,
+        save_baseline: args.contains("--save-baseline"),
+        compare: args.contains("--compare"),
+        baseline: args
+            .opt_value_from_str("--baseline")?
+            .unwrap_or_else(|| PathBuf::from(baseline::DEFAULT_PATH)),
+        threshold: args
+            .opt_value_from_str("--threshold")?
+            .unwrap_or(baseline::DEFAULT_THRESHOLD),
+    })
+}
+
+/// Reads `--year`/`-y`, defaulting to the repository's primary year.
+fn parse_year(args: &mut Arguments) -> Result<u16, pico_args::Error> {
+    Ok(args
+        .opt_value_from_str(["-y", "--year"])?
+        .unwrap_or(advent_of_code::YEAR))
+}
+
+fn exit_with_arg_error(e: pico_args::Error) -> ! {
+    eprintln!("Failed to process arguments: {}", e);
+    process::exit(1);
+}
+
+/// Prints how each part compares to the baseline and returns whether any part regressed.
+/// Goes to stderr unless the output is pretty, so json/csv output stays parseable.
+fn print_comparisons(comparisons: &[Comparison], args: &RunArgs) -> bool {
+    let print = |line: String| {
+        if args.format == Format::Pretty {
+            println!("{}", line);
+        } else {
+            eprintln!("{}", line);
+        }
+    };
+
+    print(format!(
+        "{}Compared to \"{}\" (threshold: {}%):{}",
+        ANSI_BOLD,
+        args.baseline.display(),
+        args.threshold,
+        ANSI_RESET
+    ));
+    for c in comparisons {
+        print(format!(
+            "Day {:02} Part {}: {:.2?} -> {:.2?} ({:+.1}%){}",
+            c.day,
+            c.part,
+            c.baseline,
+            c.current,
+            c.change(),
+            if c.regressed { " REGRESSED" } else { "" }
+        ));
+    }
+
+    comparisons.iter().any(|c| c.regressed)
+}
+
+/// `cargo all`: runs every registered day that has an input, or an example with `--example`.
+fn run_all(solutions: &[Solution], args: Arguments) {
+    let args = match parse_run_args(args) {
+        Ok(RunArgs { bench: Some(0), .. }) => {
+            eprintln!("`--bench` needs at least one iteration.");
+            process::exit(1);
+        }
+        Ok(args) => args,
+        Err(e) => exit_with_arg_error(e),
+    };
+
+    let pretty = args.format == Format::Pretty;
+
+    let mut skipped = Vec::new();
+    let reports: Vec<PartReport> = (1..=25)
+        .flat_map(|day| {
+            if pretty {
+                println!("----------");
+                println!("{}| Day {:02} |{}", ANSI_BOLD, day, ANSI_RESET);
+                println!("----------");
+            }
+
+            let solution = registry::find(solutions, args.year, day);
+            let folder = if args.example { "examples" } else { "inputs" };
+            let input =
+                solution.and_then(|_| advent_of_code::try_read_file(args.year, folder, day).ok());
+
+            match (solution, input) {
+                (Some(solution), Some(input)) => {
+                    registry::run_day(solution, &input, args.bench, |report| {
+                        if pretty {
+                            advent_of_code::print_part(report);
+                        }
+                    })
+                }
+                (solution, _) => {
+                    if pretty {
+                        println!("Not solved.");
+                    }
+                    skipped.push(Skipped {
+                        year: args.year,
+                        day,
+                        reason: match solution {
+                            Some(_) => SkipReason::MissingInput,
+                            None => SkipReason::Unregistered,
+                        },
+                    });
+                    vec![]
+                }
+            }
+        })
+        .collect();
+
+    match args.format {
+        Format::Pretty => println!(
+            "{}Total:{} {}{:.2}ms{}",
+            ANSI_BOLD,
+            ANSI_RESET,
+            ANSI_ITALIC,
+            report::total_elapsed(&reports).as_secs_f64() * 1000_f64,
+            ANSI_RESET
+        ),
+        Format::Json => println!("{}", report::to_json(&reports, &skipped)),
+        Format::Csv => print!("{}", report::to_csv(&reports, &skipped)),
+    }
+
+    let mut regressed = false;
+
+    if args.compare {
+        match Baseline::load(&args.baseline) {
+            Ok(previous) => {
+                let comparisons = baseline::compare(&previous, &reports, args.threshold);
+                regressed = print_comparisons(&comparisons, &args);
+            }
+            Err(e) => {
+                eprintln!(
+                    "Failed to read baseline \"{}\": {}",
+                    args.baseline.display(),
+                    e
+                );
+                process::exit(1);
+            }
+        }
+    }
+
+    if args.save_baseline {
+        match Baseline::from_reports(&reports).save(&args.baseline) {
+            Ok(_) => eprintln!("Saved baseline to \"{}\"", args.baseline.display()),
+            Err(e) => {
+                eprintln!(
+                    "Failed to write baseline \"{}\": {}",
+                    args.baseline.display(),
+                    e
+                );
+                process::exit(1);
+            }
+        }
+    }
+
+    if regressed {
+        process::exit(1);
+    }
+}
+
+/// `cargo verify [day]`: checks answers for real inputs against `src/yYYYY/answers.toml`.
+fn verify(solutions: &[Solution], mut args: Arguments) {
+    let (year, day): (u16, Option<u8>) =
+        match parse_year(&mut args).and_then(|year| Ok((year, args.opt_free_from_str()?))) {
+            Ok(args) => args,
+            Err(e) => exit_with_arg_error(e),
+        };
+
+    if let Some(day) = day {
+        if registry::find(solutions, year, day).is_none() {
+            eprintln!("Day {:02} has no registered solution.", day);
+            process::exit(1);
+        }
+    }
+
+    let answers_path = answers::path(year);
+    let answers = match Answers::load(&answers_path) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!(
+                "Failed to read answers \"{}\": {}",
+                answers_path.display(),
+                e
+            );
+            process::exit(1);
+        }
+    };
+
+    let mut failed = false;
+
+    for day in day.map_or(1..=25, |d| d..=d) {
+        let Some(solution) = registry::find(solutions, year, day) else {
+            continue;
+        };
+        let Ok(input) = advent_of_code::try_read_file(year, "inputs", day) else {
+            println!("Day {:02}: no input, skipped.", day);
+            continue;
+        };
+
+        let reports = registry::run_day(solution, &input, None, |_| {});
+        for part in 1..=2 {
+            let Some(report) = reports.iter().find(|r| r.part == part) else {
+                println!("Day {:02} Part {}: panicked", day, part);
+                failed = true;
+                continue;
+            };
+            if let Some(error) = &report.error {
+                println!(
+                    "Day {:02} Part {}: {}error{}: {}",
+                    day, part, ANSI_BOLD, ANSI_RESET, error
+                );
+                failed = true;
+                continue;
+            }
+            match answers.verify(report) {
+                Verdict::Match => println!("Day {:02} Part {}: match", day, part),
+                Verdict::Mismatch { expected } => {
+                    println!(
+                        "Day {:02} Part {}: {}MISMATCH{} (expected: {}, got: {})",
+                        day,
+                        part,
+                        ANSI_BOLD,
+                        ANSI_RESET,
+                        expected,
+                        report.answer.as_deref().unwrap_or("not solved")
+                    );
+                    failed = true;
+                }
+                Verdict::Missing => println!(
+                    "Day {:02} Part {}: missing (got: {})",
+                    day,
+                    part,
+                    report.answer.as_deref().unwrap_or("not solved")
+                ),
+            }
+        }
+    }
+
+    if failed {
+        process::exit(1);
+    }
+}
+
+/// `cargo readme`: updates the stars table in `README.md` from which parts currently return `Some`.
+/// Days without an input keep their row.
+fn update_readme(solutions: &[Solution], mut args: Arguments) {
+    let timings = args.contains("--timings");
+    let year = match parse_year(&mut args) {
+        Ok(year) => year,
+        Err(e) => exit_with_arg_error(e),
+    };
+    let readme_path = "README.md";
+
+    let mut ran = Vec::new();
+    let reports: Vec<PartReport> = solutions
+        .iter()
+        .filter(|solution| solution.year == year)
+        .flat_map(
+            |solution| match advent_of_code::try_read_file(year, "inputs", solution.day) {
+                Ok(input) => {
+                    ran.push(solution.day);
+                    registry::run_day(solution, &input, None, |_| {})
                 }
+                Err(_) => vec![],
+            },
+        )
+        .collect();
+
+    let rows = readme::stars_from_reports(&reports);
+
+    let updated = fs::read_to_string(readme_path)
+        .map_err(|e| e.to_string())
+        .and_then(|contents| {
+            let table = readme::merge_table(&contents, year, &ran, &rows, timings)?;
+            readme::replace_table(&contents, &table)
+        });
+
+    match updated.and_then(|contents| fs::write(readme_path, contents).map_err(|e| e.to_string())) {
+        Ok(_) => println!(
+            "🎄 Updated \"{}\" with {} solved of the {} days that have an input.",
+            readme_path,
+            rows.len(),
+            ran.len()
+        ),
+        Err(e) => {
+            eprintln!("Failed to update \"{}\": {}", readme_path, e);
+            process::exit(1);
+        }
+    }
+}
+
+/// `cargo aoc status`: prints one row per day with what exists on disk and the solved parts and
+/// timings of the saved baseline. With `--run`, days with a solution and an input are run instead,
+/// and their answers are checked against `src/yYYYY/answers.toml`.
+fn status(solutions: &[Solution], mut args: Arguments) {
+    let run = args.contains("--run");
+    let (year, baseline_path) = match parse_year(&mut args).and_then(|year| {
+        let path: Option<PathBuf> = args.opt_value_from_str("--baseline")?;
+        Ok((
+            year,
+            path.unwrap_or_else(|| PathBuf::from(baseline::DEFAULT_PATH)),
+        ))
+    }) {
+        Ok(args) => args,
+        Err(e) => exit_with_arg_error(e),
+    };
+
+    let baseline = Baseline::load(&baseline_path).unwrap_or_else(|e| {
+        if e.kind() != io::ErrorKind::NotFound {
+            eprintln!("Ignoring baseline \"{}\": {}", baseline_path.display(), e);
+        }
+        Baseline { parts: vec![] }
+    });
+
+    let answers_path = answers::path(year);
+    let answers = match Answers::load(&answers_path) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!(
+                "Failed to read answers \"{}\": {}",
+                answers_path.display(),
+                e
             );
+            process::exit(1);
+        }
+    };
 
-            if is_empty {
-                0_f64
-            } else {
-                advent_of_code::parse_exec_time(&output)
+    let rows: Vec<DayStatus> = (1..=25)
+        .map(|day| {
+            let row = DayStatus::new(year, day, &DayFiles::probe(year, day), &baseline);
+            if !run {
+                return row;
             }
+            let input = advent_of_code::try_read_file(year, "inputs", day)
+                .ok()
+                .filter(|input| !input.trim().is_empty());
+            let reports = match (registry::find(solutions, year, day), input) {
+                (Some(solution), Some(input)) => registry::run_day(solution, &input, None, |_| {}),
+                _ => vec![],
+            };
+            row.with_run(&reports, &answers)
         })
-        .sum();
+        .collect();
+
+    println!("{}Advent of Code {}{}", ANSI_BOLD, year, ANSI_RESET);
+    print!("{}", status::render(&rows));
+}
 
-    println!(
-        "{}Total:{} {}{:.2}ms{}",
-        ANSI_BOLD, ANSI_RESET, ANSI_ITALIC, total, ANSI_RESET
-    );
+/// `cargo submit <day> <part>`: solves a part for the real input and posts the answer,
+/// unless `src/yYYYY/submissions.json` shows it was already rejected.
+fn submit_answer(solutions: &[Solution], mut args: Arguments) {
+    let (year, day, part): (u16, u8, u8) = match parse_year(&mut args)
+        .and_then(|year| Ok((year, args.free_from_str()?, args.free_from_str()?)))
+    {
+        Ok(args) => args,
+        Err(e) => exit_with_arg_error(e),
+    };
+
+    if !(1..=2).contains(&part) {
+        eprintln!("Part has to be 1 or 2.");
+        process::exit(1);
+    }
+    let Some(solution) = registry::find(solutions, year, day) else {
+        eprintln!("Day {:02} has no registered solution.", day);
+        process::exit(1);
+    };
+    let input = match advent_of_code::try_read_file(year, "inputs", day) {
+        Ok(input) => input,
+        Err(e) => {
+            eprintln!("Failed to read input of day {:02}: {}", day, e);
+            process::exit(1);
+        }
+    };
+
+    let reports = registry::run_parts(solution, &input, &[part], None, advent_of_code::print_part);
+    let Some(answer) = reports
+        .into_iter()
+        .find(|r| r.part == part)
+        .and_then(|r| r.answer)
+    else {
+        process::exit(1);
+    };
+
+    let submissions_path = submit::path(year);
+    let mut submissions = match Submissions::load(&submissions_path) {
+        Ok(submissions) => submissions,
+        Err(e) => {
+            eprintln!(
+                "Failed to read submissions \"{}\": {}",
+                submissions_path.display(),
+                e
+            );
+            process::exit(1);
+        }
+    };
+    if let Err(reason) = submissions.check(day, part, &answer) {
+        eprintln!("Not submitting: {}", reason);
+        process::exit(1);
+    }
+
+    let response = Client::from_env().and_then(|client| {
+        println!(
+            "Submitting {} to \"{}\"...",
+            answer,
+            client.answer_url(year, day)
+        );
+        client.submit_answer(year, day, part, &answer)
+    });
+    let outcome = match response {
+        Ok(page) => submit::parse_response(&page),
+        Err(e) => {
+            eprintln!("Failed to submit answer: {}", e);
+            process::exit(1);
+        }
+    };
+
+    println!("{}", outcome);
+    submissions.record(day, part, &answer, outcome.clone());
+    if let Err(e) = submissions.save(&submissions_path) {
+        eprintln!(
+            "Failed to write submissions \"{}\": {}",
+            submissions_path.display(),
+            e
+        );
+        process::exit(1);
+    }
+
+    if outcome != Outcome::Correct {
+        process::exit(1);
+    }
+}
+
+fn main() {
+    let mut args = Arguments::from_env();
+    let solutions = solutions();
+
+    match args.subcommand() {
+        Ok(None) => run_all(&solutions, args),
+        Ok(Some(command)) if command == "verify" => verify(&solutions, args),
+        Ok(Some(command)) if command == "readme" => update_readme(&solutions, args),
+        Ok(Some(command)) if command == "submit" => submit_answer(&solutions, args),
+        Ok(Some(command)) if command == "status" => status(&solutions, args),
+        Ok(Some(command)) => {
+            eprintln!(
+                "Unknown command \"{}\". Available commands: verify, readme, submit, status",
+                command
+            );
+            process::exit(1);
+        }
+        Err(e) => exit_with_arg_error(e),
+    }
 }
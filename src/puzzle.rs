@@ -0,0 +1,262 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::path::PathBuf;
+
+/// Location of a day's puzzle description, e.g. `src/y2022/puzzles/01.md`.
+pub fn path(year: u16, day: u8) -> PathBuf {
+    crate::year_dir(year)
+        .join("puzzles")
+        .join(format!("{:02}.md", day))
+}
+
+enum Token<'a> {
+    Text(&'a str),
+    Open(&'a str, &'a str),
+    Close(&'a str),
+}
+
+/// Splits html into text and tags. Comments, doctypes and malformed tags are skipped.
+fn tokens(html: &str) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut rest = html;
+
+    while !rest.is_empty() {
+        let Some(start) = rest.find('<') else {
+            tokens.push(Token::Text(rest));
+            break;
+        };
+        if start > 0 {
+            tokens.push(Token::Text(&rest[..start]));
+        }
+        let Some(end) = rest[start..].find('>').map(|end| start + end) else {
+            break;
+        };
+
+        let tag = rest[start + 1..end].trim_end_matches('/').trim();
+        if let Some(name) = tag.strip_prefix('/') {
+            tokens.push(Token::Close(name.trim()));
+        } else if !tag.starts_with('!') {
+            let (name, attrs) = tag.split_once(char::is_whitespace).unwrap_or((tag, ""));
+            tokens.push(Token::Open(name, attrs));
+        }
+        rest = &rest[end + 1..];
+    }
+
+    tokens
+}
+
+/// Value of the attribute `name` in a tag's attributes, e.g. `href` of `href="/2022"`.
+fn attribute<'a>(attrs: &'a str, name: &str) -> Option<&'a str> {
+    let start = attrs.find(&format!("{}=\"", name))? + name.len() + 2;
+    let end = start + attrs[start..].find('"')?;
+    Some(&attrs[start..end])
+}
+
+fn decode_entities(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/// The contents of every `<article>` of a puzzle page. Part two gets its own article once unlocked.
+fn articles(html: &str) -> Vec<&str> {
+    let mut articles = Vec::new();
+    let mut rest = html;
+
+    while let Some(start) = rest.find("<article") {
+        let Some(open_end) = rest[start..].find('>').map(|end| start + end + 1) else {
+            break;
+        };
+        let Some(close) = rest[open_end..]
+            .find("</article>")
+            .map(|end| open_end + end)
+        else {
+            break;
+        };
+        articles.push(&rest[open_end..close]);
+        rest = &rest[close..];
+    }
+
+    articles
+}
+
+/// Whether part two of the puzzle is unlocked, i.e. part one was solved.
+pub fn has_part_two(html: &str) -> bool {
+    articles(html).len() > 1
+}
+
+/// Converts the puzzle descriptions of a puzzle page to Markdown.
+pub fn to_markdown(html: &str) -> String {
+    let mut md = String::new();
+
+    for article in articles(html) {
+        let mut in_pre = false;
+        let mut in_code = false;
+        let mut links: Vec<Option<&str>> = Vec::new();
+
+        for token in tokens(article) {
+            match token {
+                Token::Text(text) if in_pre => md.push_str(&decode_entities(text)),
+                Token::Text(text) => {
+                    let text = decode_entities(text).replace('\n', " ");
+                    if md.is_empty() || md.ends_with('\n') {
+                        md.push_str(text.trim_start());
+                    } else {
+                        md.push_str(&text);
+                    }
+                }
+                Token::Open("h2", _) => md.push_str("## "),
+                Token::Close("h2" | "p") => md.push_str("\n\n"),
+                Token::Open("pre", _) => {
+                    in_pre = true;
+                    md.push_str("```\n");
+                }
+                Token::Close("pre") => {
+                    in_pre = false;
+                    if !md.ends_with('\n') {
+                        md.push('\n');
+                    }
+                    md.push_str("```\n\n");
+                }
+                Token::Open("code", _) | Token::Close("code") if !in_pre => {
+                    in_code = matches!(token, Token::Open(..));
+                    md.push('`');
+                }
+                Token::Open("em", _) | Token::Close("em") if !in_pre && !in_code => md.push('*'),
+                Token::Open("li", _) => md.push_str("- "),
+                Token::Close("li" | "ul") => md.push('\n'),
+                Token::Open("a", attrs) => {
+                    let href = attribute(attrs, "href");
+                    if href.is_some() {
+                        md.push('[');
+                    }
+                    links.push(href);
+                }
+                Token::Close("a") => {
+                    if let Some(Some(href)) = links.pop() {
+                        md.push_str(&format!("]({})", href));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    let lines: Vec<&str> = md.lines().map(|l| l.trim_end()).collect();
+    lines.join("\n").trim().to_owned() + "\n"
+}
+
+/// The puzzle's name from a description converted with [`to_markdown`], e.g. `Calorie Counting`.
+pub fn title(markdown: &str) -> Option<String> {
+    let heading = markdown.lines().next()?.strip_prefix("## --- ")?;
+    let (_, name) = heading.strip_suffix(" ---")?.split_once(": ")?;
+    Some(name.to_owned())
+}
+
+/// The first `<pre><code>` block of the puzzle descriptions, which usually holds the example input.
+pub fn example(html: &str) -> Option<String> {
+    let article = articles(html).into_iter().next()?;
+    let start = article.find("<pre><code>")? + "<pre><code>".len();
+    let end = start + article[start..].find("</code></pre>")?;
+
+    let text: String = tokens(&article[start..end])
+        .into_iter()
+        .filter_map(|token| match token {
+            Token::Text(text) => Some(text),
+            _ => None,
+        })
+        .collect();
+
+    Some(decode_entities(&text))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PAGE: &str = r#"<!DOCTYPE html>
+<html lang="en-us">
+<head><title>Day 1 - Advent of Code 2022</title></head>
+<body>
+<main>
+<article class="day-desc"><h2>--- Day 1: Counting Things ---</h2><p>The elves need <em>help</em> with a <a href="/2022/about">list</a>.</p>
+<p>For example:</p>
+<pre><code>1000
+2000
+
+<em>3000</em> &lt; 4000
+</code></pre>
+<ul>
+<li>The first group has <code><em>3000</em></code> items.</li>
+<li>The second group is <span title="no really">empty</span>.</li>
+</ul>
+<p>What is the <em>total</em>?</p>
+</article>
+<p>Your puzzle answer was <code>24000</code>.</p>
+<article class="day-desc"><h2 id="part2">--- Part Two ---</h2><p>Now count <code>a &amp; b</code>.</p>
+</article>
+</main>
+</body>
+</html>"#;
+
+    #[test]
+    fn test_to_markdown() {
+        assert_eq!(
+            to_markdown(PAGE),
+            "## --- Day 1: Counting Things ---
+
+The elves need *help* with a [list](/2022/about).
+
+For example:
+
+```
+1000
+2000
+
+3000 < 4000
+```
+
+- The first group has `3000` items.
+- The second group is empty.
+
+What is the *total*?
+
+## --- Part Two ---
+
+Now count `a & b`.
+"
+        );
+    }
+
+    #[test]
+    fn test_title() {
+        assert_eq!(
+            title(&to_markdown(PAGE)),
+            Some("Counting Things".to_owned())
+        );
+        assert_eq!(title("## --- Part Two ---\n"), None);
+    }
+
+    #[test]
+    fn test_example() {
+        assert_eq!(
+            example(PAGE),
+            Some("1000\n2000\n\n3000 < 4000\n".to_owned())
+        );
+        assert_eq!(example("<article><p>No example.</p></article>"), None);
+    }
+
+    #[test]
+    fn test_has_part_two() {
+        assert!(has_part_two(PAGE));
+        assert!(!has_part_two(
+            "<main><article><p>Part one.</p></article></main>"
+        ));
+    }
+}
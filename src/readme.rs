@@ -0,0 +1,372 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::time::Duration;
+
+use crate::report::{PartReport, Status};
+
+pub const MARKER: &str = "<!--- advent_readme_stars table --->";
+
+/// Which parts of a day are solved, as shown in one row of the stars table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayStars {
+    pub day: u8,
+    pub part_one: bool,
+    pub part_two: bool,
+    pub elapsed: Duration,
+}
+
+/// Collects one row per day that has at least one solved part, ordered by day.
+/// A day's time includes parsing its input, if it was reported separately.
+pub fn stars_from_reports(reports: &[PartReport]) -> Vec<DayStars> {
+    let mut rows: Vec<DayStars> = Vec::new();
+    for report in reports.iter().filter(|r| r.status() == Status::Solved) {
+        let index = match rows.iter().position(|r| r.day == report.day) {
+            Some(index) => index,
+            None => {
+                rows.push(DayStars {
+                    day: report.day,
+                    part_one: false,
+                    part_two: false,
+                    elapsed: Duration::ZERO,
+                });
+                rows.len() - 1
+            }
+        };
+        let row = &mut rows[index];
+        match report.part {
+            1 => row.part_one = true,
+            2 => row.part_two = true,
+            _ => {}
+        }
+        row.elapsed += report.elapsed;
+    }
+    for report in reports.iter().filter(|r| r.status() == Status::Parsed) {
+        if let Some(row) = rows.iter_mut().find(|r| r.day == report.day) {
+            row.elapsed += report.elapsed;
+        }
+    }
+    rows.sort_by_key(|r| r.day);
+    rows
+}
+
+fn star(solved: bool) -> &'static str {
+    if solved {
+        "⭐"
+    } else {
+        " "
+    }
+}
+
+/// Renders the results table, optionally with the combined time of each day's solved parts.
+pub fn render_table(year: u16, rows: &[DayStars], timings: bool) -> String {
+    let mut table = format!("## {} Results\n\n", year);
+    if timings {
+        table.push_str("| Day | Part 1 | Part 2 | Time |\n| :---: | :---: | :---: | :---: |\n");
+    } else {
+        table.push_str("| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n");
+    }
+
+    for row in rows {
+        table.push_str(&render_row(year, row, timings));
+        table.push('\n');
+    }
+    table
+}
+
+fn render_row(year: u16, row: &DayStars, timings: bool) -> String {
+    let mut line = format!(
+        "| [Day {day}](https://adventofcode.com/{year}/day/{day}) | {} | {} |",
+        star(row.part_one),
+        star(row.part_two),
+        day = row.day,
+        year = year,
+    );
+    if timings {
+        line.push_str(&format!(" {:.2?} |", row.elapsed));
+    }
+    line
+}
+
+/// The day a row of the table links to.
+fn row_day(line: &str) -> Option<u8> {
+    line.strip_prefix("| [Day ")
+        .and_then(|rest| rest.split(']').next())
+        .and_then(|d| d.parse::<u8>().ok())
+}
+
+/// Renders the table of `year` like [`render_table`], with the `rows` of the days that `ran`.
+/// The rows already in the table of `readme` are kept for days that didn't run, e.g. because
+/// their input is missing, and show no stars for days that ran without solving a part.
+pub fn merge_table(
+    readme: &str,
+    year: u16,
+    ran: &[u8],
+    rows: &[DayStars],
+    timings: bool,
+) -> Result<String, String> {
+    let (start, end) = table_bounds(readme)?;
+    let existing = &readme[start..end];
+    let heading = format!("## {} Results", year);
+
+    let mut lines: Vec<(u8, String)> = Vec::new();
+    if existing.lines().any(|l| l == heading) {
+        for line in existing.lines() {
+            let Some(day) = row_day(line) else {
+                continue;
+            };
+            let line = match rows.iter().find(|r| r.day == day) {
+                Some(row) => render_row(year, row, timings),
+                None if ran.contains(&day) => unsolved_row(year, day, timings),
+                None => kept_row(line, timings),
+            };
+            lines.push((day, line));
+        }
+    }
+    for row in rows {
+        if !lines.iter().any(|(day, _)| *day == row.day) {
+            lines.push((row.day, render_row(year, row, timings)));
+        }
+    }
+    lines.sort_by_key(|(day, _)| *day);
+
+    let mut table = render_table(year, &[], timings);
+    for (_, line) in lines {
+        table.push_str(&line);
+        table.push('\n');
+    }
+    Ok(table)
+}
+
+/// A row without stars, with `-` as its time.
+fn unsolved_row(year: u16, day: u8, timings: bool) -> String {
+    let row = DayStars {
+        day,
+        part_one: false,
+        part_two: false,
+        elapsed: Duration::ZERO,
+    };
+    let mut line = render_row(year, &row, false);
+    if timings {
+        line.push_str(" - |");
+    }
+    line
+}
+
+/// Adds or drops the time column of a kept row, so it matches the header.
+fn kept_row(line: &str, timings: bool) -> String {
+    match (timings, line.matches('|').count()) {
+        (true, 4) => format!("{} - |", line),
+        (false, 5) => {
+            let time = line
+                .trim_end_matches('|')
+                .rfind('|')
+                .map_or(line.len(), |i| i + 1);
+            line[..time].to_owned()
+        }
+        _ => line.to_owned(),
+    }
+}
+
+/// Adds an unsolved row for `day` to the table in `readme`, ordered by day.
+/// Returns `None` when the table already has the day or is not the table of `year`.
+pub fn add_row(readme: &str, year: u16, day: u8) -> Result<Option<String>, String> {
+    let start = readme
+        .find(MARKER)
+        .ok_or_else(|| format!("could not find \"{}\"", MARKER))?;
+    let end = readme[start + MARKER.len()..]
+        .find(MARKER)
+        .ok_or_else(|| format!("could not find closing \"{}\"", MARKER))?
+        + start
+        + MARKER.len();
+
+    let mut lines: Vec<&str> = readme[start..end].lines().collect();
+    let heading = format!("## {} Results", year);
+    let link = format!("| [Day {}](", day);
+    if !lines.contains(&heading.as_str()) || lines.iter().any(|l| l.starts_with(&link)) {
+        return Ok(None);
+    }
+
+    let index = lines
+        .iter()
+        .position(|l| row_day(l).is_some_and(|d| d > day))
+        .or_else(|| {
+            lines
+                .iter()
+                .rposition(|l| l.starts_with("| "))
+                .map(|i| i + 1)
+        })
+        .ok_or_else(|| format!("could not find the rows of \"{}\"", heading))?;
+
+    let timings = lines
+        .iter()
+        .any(|l| l.starts_with("| Day |") && l.contains("| Time |"));
+    let line = unsolved_row(year, day, timings);
+    lines.insert(index, &line);
+
+    Ok(Some(format!(
+        "{}{}\n{}",
+        &readme[..start],
+        lines.join("\n"),
+        &readme[end..]
+    )))
+}
+
+/// Where the text between the two table markers in `readme` starts and ends.
+fn table_bounds(readme: &str) -> Result<(usize, usize), String> {
+    let start = readme
+        .find(MARKER)
+        .ok_or_else(|| format!("could not find \"{}\"", MARKER))?
+        + MARKER.len();
+    let end = readme[start..]
+        .find(MARKER)
+        .ok_or_else(|| format!("could not find closing \"{}\"", MARKER))?
+        + start;
+    Ok((start, end))
+}
+
+/// Replaces everything between the two table markers in `readme` with `table`.
+pub fn replace_table(readme: &str, table: &str) -> Result<String, String> {
+    let (start, end) = table_bounds(readme)?;
+    Ok(format!("{}\n{}{}", &readme[..start], table, &readme[end..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report(day: u8, part: u8, solved: bool) -> PartReport {
+        PartReport {
+            year: 2022,
+            day,
+            part,
+            answer: solved.then(|| "1".to_owned()),
+            error: None,
+            elapsed: Duration::from_millis(1),
+            bench: None,
+        }
+    }
+
+    #[test]
+    fn test_stars_from_reports() {
+        let rows = stars_from_reports(&[
+            report(15, 1, true),
+            report(15, 2, false),
+            report(8, 1, false),
+            report(8, 2, false),
+            report(8, 0, false),
+            report(1, 0, false),
+            report(1, 1, true),
+            report(1, 2, true),
+        ]);
+        assert_eq!(
+            rows,
+            vec![
+                DayStars {
+                    day: 1,
+                    part_one: true,
+                    part_two: true,
+                    elapsed: Duration::from_millis(3)
+                },
+                DayStars {
+                    day: 15,
+                    part_one: true,
+                    part_two: false,
+                    elapsed: Duration::from_millis(1)
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_add_row() {
+        let rows = stars_from_reports(&[report(1, 1, true), report(15, 1, true)]);
+        let readme = format!(
+            "# Title\n\n{}\n{}{}\n",
+            MARKER,
+            render_table(2022, &rows, false),
+            MARKER
+        );
+
+        let updated = add_row(&readme, 2022, 8).unwrap().unwrap();
+        assert!(updated.contains(
+            "| ⭐ |   |\n| [Day 8](https://adventofcode.com/2022/day/8) |   |   |\n| [Day 15]"
+        ));
+        assert!(updated.ends_with(&format!("|   |\n{}\n", MARKER)));
+        assert_eq!(add_row(&updated, 2022, 8), Ok(None));
+        assert_eq!(add_row(&readme, 2021, 8), Ok(None));
+
+        let readme = format!(
+            "{}\n{}{}\n",
+            MARKER,
+            render_table(2022, &rows, true),
+            MARKER
+        );
+        let updated = add_row(&readme, 2022, 25).unwrap().unwrap();
+        assert!(updated.ends_with(&format!(
+            "(https://adventofcode.com/2022/day/25) |   |   | - |\n{}\n",
+            MARKER
+        )));
+    }
+
+    #[test]
+    fn test_render_and_replace_table() {
+        let readme = format!("# Title\n\n{}\nstale\n{}\n\n---\n", MARKER, MARKER);
+        let rows = stars_from_reports(&[report(15, 1, true), report(15, 2, false)]);
+
+        let updated = replace_table(&readme, &render_table(2022, &rows, true)).unwrap();
+        assert_eq!(
+            updated,
+            format!(
+                "# Title\n\n{}\n## 2022 Results\n\n| Day | Part 1 | Part 2 | Time |\n| :---: | :---: | :---: | :---: |\n| [Day 15](https://adventofcode.com/2022/day/15) | ⭐ |   | 1.00ms |\n{}\n\n---\n",
+                MARKER, MARKER
+            )
+        );
+    }
+
+    #[test]
+    fn test_replace_table_without_markers() {
+        assert!(replace_table("# Title", "").is_err());
+    }
+
+    #[test]
+    fn test_merge_table() {
+        let rows = stars_from_reports(&[
+            report(1, 1, true),
+            report(7, 1, true),
+            report(7, 2, true),
+            report(15, 1, true),
+        ]);
+        let readme = format!(
+            "{}\n{}{}\n",
+            MARKER,
+            render_table(2022, &rows, false),
+            MARKER
+        );
+
+        // Day 7's input is missing, day 15 no longer solves and day 20 is new.
+        let rows =
+            stars_from_reports(&[report(1, 1, true), report(1, 2, true), report(20, 1, true)]);
+        let table = merge_table(&readme, 2022, &[1, 15, 20], &rows, false).unwrap();
+        assert_eq!(
+            table,
+            "## 2022 Results\n\n| Day | Part 1 | Part 2 |\n| :---: | :---: | :---: |\n\
+             | [Day 1](https://adventofcode.com/2022/day/1) | ⭐ | ⭐ |\n\
+             | [Day 7](https://adventofcode.com/2022/day/7) | ⭐ | ⭐ |\n\
+             | [Day 15](https://adventofcode.com/2022/day/15) |   |   |\n\
+             | [Day 20](https://adventofcode.com/2022/day/20) | ⭐ |   |\n"
+        );
+
+        let table = merge_table(&readme, 2022, &[1, 15, 20], &rows, true).unwrap();
+        assert!(table.contains("| [Day 7](https://adventofcode.com/2022/day/7) | ⭐ | ⭐ | - |\n"));
+        assert!(table.contains("| [Day 15](https://adventofcode.com/2022/day/15) |   |   | - |\n"));
+        let readme = replace_table(&readme, &table).unwrap();
+        let table = merge_table(&readme, 2022, &[1], &rows[..1], false).unwrap();
+        assert!(table.contains("| [Day 7](https://adventofcode.com/2022/day/7) | ⭐ | ⭐ |\n"));
+        assert!(table.contains("| [Day 20](https://adventofcode.com/2022/day/20) | ⭐ |   |\n"));
+
+        let table = merge_table(&readme, 2021, &[1], &rows[..1], false).unwrap();
+        assert_eq!(table, render_table(2021, &rows[..1], false));
+    }
+}
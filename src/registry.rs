@@ -0,0 +1,207 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fmt::Display;
+use std::panic;
+
+use crate::report::PartReport;
+use crate::{bench, Day, Error};
+
+/// A type-erased, timed runner for the selected parts of a day, with `--bench` iterations.
+/// It passes each report to the callback as it completes; see [`run_functions`] and [`run_parsed`].
+/// Parts other than 1 and 2 are skipped.
+pub type Runner = fn(&str, &[u8], Option<u32>, &mut dyn FnMut(PartReport));
+
+/// A day, as registered with the all-days runner in `src/main.rs`.
+pub struct Solution {
+    pub year: u16,
+    pub day: u8,
+    pub run: Runner,
+}
+
+/// Looks up the solution registered for `day` of `year`, if any.
+pub fn find(solutions: &[Solution], year: u16, day: u8) -> Option<&Solution> {
+    solutions.iter().find(|s| s.year == year && s.day == day)
+}
+
+/// Runs (or benchmarks, given `bench`) both parts of a day, calling `on_report` as each part completes.
+/// A panicking solution only aborts its own day; the reports of parts that completed are returned.
+pub fn run_day(
+    solution: &Solution,
+    input: &str,
+    bench: Option<u32>,
+    on_report: impl FnMut(&PartReport),
+) -> Vec<PartReport> {
+    run_parts(solution, input, &[1, 2], bench, on_report)
+}
+
+/// Like [`run_day`], for only the given `parts`.
+pub fn run_parts(
+    solution: &Solution,
+    input: &str,
+    parts: &[u8],
+    bench: Option<u32>,
+    mut on_report: impl FnMut(&PartReport),
+) -> Vec<PartReport> {
+    let mut reports = Vec::new();
+    let _ = panic::catch_unwind(panic::AssertUnwindSafe(|| {
+        (solution.run)(input, parts, bench, &mut |report| {
+            on_report(&report);
+            reports.push(report);
+        })
+    }));
+    reports
+}
+
+/// Runs a day given as two functions of the input text.
+#[allow(clippy::too_many_arguments)]
+pub fn run_functions<T1: Display, T2: Display>(
+    year: u16,
+    day: u8,
+    input: &str,
+    parts: &[u8],
+    bench: Option<u32>,
+    on_report: &mut dyn FnMut(PartReport),
+    part_one: impl Fn(&str) -> Result<Option<T1>, Error>,
+    part_two: impl Fn(&str) -> Result<Option<T2>, Error>,
+) {
+    for part in parts {
+        let report = match part {
+            1 => bench::run(|| crate::run_part(year, day, 1, &part_one, input), bench),
+            2 => bench::run(|| crate::run_part(year, day, 2, &part_two, input), bench),
+            _ => continue,
+        };
+        on_report(report);
+    }
+}
+
+/// Runs a [`Day`], parsing `input` once for all `parts` and reporting the parsing first.
+/// When parsing fails, each part reports the parse error instead.
+pub fn run_parsed<D: Day>(
+    year: u16,
+    day: u8,
+    input: &str,
+    parts: &[u8],
+    bench: Option<u32>,
+    on_report: &mut dyn FnMut(PartReport),
+) {
+    let (parsed, report) = crate::run_parse(year, day, D::parse, input);
+    let parsed = match parsed {
+        Ok(parsed) => {
+            on_report(match bench {
+                Some(iterations) => bench::bench(
+                    || crate::run_parse(year, day, D::parse, input).1,
+                    iterations,
+                ),
+                None => report,
+            });
+            parsed
+        }
+        Err(error) => {
+            for part in parts.iter().filter(|part| matches!(part, 1 | 2)) {
+                on_report(PartReport {
+                    part: *part,
+                    error: Some(error.clone()),
+                    ..report.clone()
+                });
+            }
+            return;
+        }
+    };
+
+    for part in parts {
+        let report = match part {
+            1 => bench::run(
+                || crate::run_part(year, day, 1, D::part_one, &parsed),
+                bench,
+            ),
+            2 => bench::run(
+                || crate::run_part(year, day, 2, D::part_two, &parsed),
+                bench,
+            ),
+            _ => continue,
+        };
+        on_report(report);
+    }
+}
+
+/// Builds a [`Solution`] from a module exposing `part_one` and `part_two`, or from a [`Day`].
+/// Parts that take [`crate::Params`] name the module's params type last. They run with its `EXAMPLE`
+/// when the runner was called with `--example`, and with its `INPUT` otherwise.
+/// example: `solution!(2022, 7, days::y2022::day07)`, `solution!(2022, 15, days::y2022::day15, Area)`
+/// or `solution!(2022, 13, days::y2022::day13::Day13 as Day)`
+#[macro_export]
+macro_rules! solution {
+    ($year:expr, $day:expr, $($module:ident)::+, $params:ident) => {
+        $crate::registry::Solution {
+            year: $year,
+            day: $day,
+            run: |input, parts, bench, on_report| {
+                let params: $($module)::+::$params = $crate::params(&$crate::args::input_source());
+                $crate::registry::run_functions(
+                    $year,
+                    $day,
+                    input,
+                    parts,
+                    bench,
+                    on_report,
+                    |input| $($module)::+::part_one(input, &params),
+                    |input| $($module)::+::part_two(input, &params),
+                )
+            },
+        }
+    };
+    ($year:expr, $day:expr, $($module:ident)::+ as Day) => {
+        $crate::registry::Solution {
+            year: $year,
+            day: $day,
+            run: |input, parts, bench, on_report| {
+                $crate::registry::run_parsed::<$($module)::+>($year, $day, input, parts, bench, on_report)
+            },
+        }
+    };
+    ($year:expr, $day:expr, $($module:ident)::+) => {
+        $crate::registry::Solution {
+            year: $year,
+            day: $day,
+            run: |input, parts, bench, on_report| {
+                $crate::registry::run_functions(
+                    $year,
+                    $day,
+                    input,
+                    parts,
+                    bench,
+                    on_report,
+                    $($module)::+::part_one,
+                    $($module)::+::part_two,
+                )
+            },
+        }
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(input: &str, parts: &[u8]) -> Vec<u8> {
+        let mut reported = Vec::new();
+        run_functions(
+            2022,
+            1,
+            input,
+            parts,
+            None,
+            &mut |report| reported.push(report.part),
+            |input| Ok(Some(input.len())),
+            |_| Ok(None::<u32>),
+        );
+        reported
+    }
+
+    #[test]
+    fn test_run_functions_skips_unknown_parts() {
+        assert_eq!(run("input", &[0, 2, 3, 1]), vec![2, 1]);
+    }
+}
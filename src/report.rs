@@ -0,0 +1,367 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use serde::Serialize;
+use std::str::FromStr;
+use std::time::Duration;
+
+use crate::bench::BenchStats;
+use crate::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Status {
+    Solved,
+    Unsolved,
+    Error,
+    /// The input of a [`crate::Day`] was parsed; only reported for part [`PARSE`].
+    Parsed,
+    /// The day didn't run, see [`Skipped`]; only in the json and csv output of the all-days runner.
+    Skipped,
+}
+
+/// The `part` of the report timing [`crate::Day::parse`], which runs before both parts.
+pub const PARSE: u8 = 0;
+
+/// The outcome of running one part of a day, as produced by `solve!` and the all-days runner.
+/// When benchmarked, `elapsed` is the median of all runs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartReport {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub answer: Option<String>,
+    pub error: Option<Error>,
+    pub elapsed: Duration,
+    pub bench: Option<BenchStats>,
+}
+
+impl PartReport {
+    pub fn status(&self) -> Status {
+        match (&self.answer, &self.error) {
+            (_, Some(_)) => Status::Error,
+            (Some(_), None) => Status::Solved,
+            (None, None) if self.part == PARSE => Status::Parsed,
+            (None, None) => Status::Unsolved,
+        }
+    }
+}
+
+/// Combined time of all solved parts in `reports`, including the parsing they shared.
+pub fn total_elapsed(reports: &[PartReport]) -> Duration {
+    reports
+        .iter()
+        .filter(|r| matches!(r.status(), Status::Solved | Status::Parsed))
+        .map(|r| r.elapsed)
+        .sum()
+}
+
+/// Why the all-days runner didn't run a day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SkipReason {
+    /// No solution is registered for the day.
+    Unregistered,
+    /// The day's input (or example, with `--example`) can't be read.
+    MissingInput,
+}
+
+/// A day the all-days runner skipped. It is written as a `skipped` record for each part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Skipped {
+    pub year: u16,
+    pub day: u8,
+    pub reason: SkipReason,
+}
+
+/// Output format of the all-days runner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Format {
+    #[default]
+    Pretty,
+    Json,
+    Csv,
+}
+
+impl FromStr for Format {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "pretty" => Ok(Format::Pretty),
+            "json" => Ok(Format::Json),
+            "csv" => Ok(Format::Csv),
+            _ => Err(format!(
+                "unknown format \"{}\", expected json, csv or pretty",
+                s
+            )),
+        }
+    }
+}
+
+/// A flat, serializable view of a [`PartReport`] or of a part of a [`Skipped`] day.
+#[derive(Serialize)]
+struct Record<'a> {
+    year: u16,
+    day: u8,
+    part: u8,
+    status: Status,
+    answer: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reason: Option<SkipReason>,
+    elapsed_ns: Option<u128>,
+    #[serde(flatten, skip_serializing_if = "Option::is_none")]
+    bench: Option<BenchRecord>,
+}
+
+#[derive(Serialize)]
+struct BenchRecord {
+    runs: u32,
+    min_ns: u128,
+    median_ns: u128,
+    mean_ns: u128,
+    p95_ns: u128,
+    stddev_ns: u128,
+}
+
+impl From<&BenchStats> for BenchRecord {
+    fn from(stats: &BenchStats) -> Self {
+        BenchRecord {
+            runs: stats.runs,
+            min_ns: stats.min.as_nanos(),
+            median_ns: stats.median.as_nanos(),
+            mean_ns: stats.mean.as_nanos(),
+            p95_ns: stats.p95.as_nanos(),
+            stddev_ns: stats.stddev.as_nanos(),
+        }
+    }
+}
+
+impl<'a> From<&'a PartReport> for Record<'a> {
+    fn from(report: &'a PartReport) -> Self {
+        Record {
+            year: report.year,
+            day: report.day,
+            part: report.part,
+            status: report.status(),
+            answer: report.answer.as_deref(),
+            error: report.error.as_ref().map(Error::to_string),
+            reason: None,
+            elapsed_ns: Some(report.elapsed.as_nanos()),
+            bench: report.bench.as_ref().map(BenchRecord::from),
+        }
+    }
+}
+
+/// The records of `reports` and of both parts of the `skipped` days, ordered by day.
+fn records<'a>(reports: &'a [PartReport], skipped: &[Skipped]) -> Vec<Record<'a>> {
+    let mut records: Vec<Record> = reports.iter().map(Record::from).collect();
+    for skip in skipped {
+        records.extend([1, 2].map(|part| Record {
+            year: skip.year,
+            day: skip.day,
+            part,
+            status: Status::Skipped,
+            answer: None,
+            error: None,
+            reason: Some(skip.reason),
+            elapsed_ns: None,
+            bench: None,
+        }));
+    }
+    records.sort_by_key(|r| (r.year, r.day));
+    records
+}
+
+/// Renders one JSON object per report and per part of a skipped day, as an array.
+pub fn to_json(reports: &[PartReport], skipped: &[Skipped]) -> String {
+    serde_json::to_string_pretty(&records(reports, skipped)).expect("records serialize to json")
+}
+
+/// Renders one CSV row per report and per part of a skipped day, with a header row.
+/// Benchmark columns are added when any of the reports was benchmarked.
+pub fn to_csv(reports: &[PartReport], skipped: &[Skipped]) -> String {
+    let with_bench = reports.iter().any(|r| r.bench.is_some());
+
+    let mut csv = String::from("year,day,part,status,answer,elapsed_ns");
+    if with_bench {
+        csv.push_str(",runs,min_ns,median_ns,mean_ns,p95_ns,stddev_ns");
+    }
+    csv.push('\n');
+
+    for record in records(reports, skipped) {
+        let status = match record.status {
+            Status::Solved => "solved",
+            Status::Unsolved => "unsolved",
+            Status::Error => "error",
+            Status::Parsed => "parsed",
+            Status::Skipped => "skipped",
+        };
+        csv.push_str(&format!(
+            "{},{},{},{},{},{}",
+            record.year,
+            record.day,
+            record.part,
+            status,
+            csv_field(record.answer.unwrap_or("")),
+            record
+                .elapsed_ns
+                .map_or_else(String::new, |ns| ns.to_string())
+        ));
+        if with_bench {
+            match &record.bench {
+                Some(b) => csv.push_str(&format!(
+                    ",{},{},{},{},{},{}",
+                    b.runs, b.min_ns, b.median_ns, b.mean_ns, b.p95_ns, b.stddev_ns
+                )),
+                None => csv.push_str(",,,,,,"),
+            }
+        }
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Quotes a field if it contains a separator, quote or line break (RFC 4180).
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_owned()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn reports() -> Vec<PartReport> {
+        vec![
+            PartReport {
+                year: 2022,
+                day: 5,
+                part: 1,
+                answer: Some("CMZ".to_owned()),
+                error: None,
+                elapsed: Duration::from_micros(12),
+                bench: None,
+            },
+            PartReport {
+                year: 2022,
+                day: 10,
+                part: 2,
+                answer: Some("#.\n.#".to_owned()),
+                error: None,
+                elapsed: Duration::from_nanos(800),
+                bench: None,
+            },
+            PartReport {
+                year: 2022,
+                day: 15,
+                part: 2,
+                answer: None,
+                error: None,
+                elapsed: Duration::from_nanos(50),
+                bench: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_to_csv() {
+        assert_eq!(
+            to_csv(&reports(), &[]),
+            "year,day,part,status,answer,elapsed_ns\n2022,5,1,solved,CMZ,12000\n2022,10,2,solved,\"#.\n.#\",800\n2022,15,2,unsolved,,50\n"
+        );
+    }
+
+    #[test]
+    fn test_to_json() {
+        let json: serde_json::Value = serde_json::from_str(&to_json(&reports(), &[])).unwrap();
+        assert_eq!(
+            json[1],
+            serde_json::json!({"year": 2022, "day": 10, "part": 2, "status": "solved", "answer": "#.\n.#", "elapsed_ns": 800})
+        );
+        assert_eq!(json[2]["status"], "unsolved");
+        assert_eq!(json[2]["answer"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn test_to_csv_with_bench() {
+        let mut reports = reports();
+        reports[0].bench = Some(BenchStats::from_samples(&[
+            Duration::from_micros(10),
+            Duration::from_micros(14),
+        ]));
+        let csv = to_csv(&reports, &[]);
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next(),
+            Some(
+                "year,day,part,status,answer,elapsed_ns,runs,min_ns,median_ns,mean_ns,p95_ns,stddev_ns"
+            )
+        );
+        assert_eq!(
+            lines.next(),
+            Some("2022,5,1,solved,CMZ,12000,2,10000,12000,12000,14000,2000")
+        );
+        assert!(csv.ends_with("2022,15,2,unsolved,,50,,,,,,\n"));
+    }
+
+    #[test]
+    fn test_error() {
+        let mut reports = reports();
+        reports[2].error = Some(Error::unsolvable("no beacon"));
+        assert_eq!(reports[2].status(), Status::Error);
+
+        let json: serde_json::Value = serde_json::from_str(&to_json(&reports, &[])).unwrap();
+        assert_eq!(json[2]["status"], "error");
+        assert_eq!(json[2]["error"], "no beacon");
+        assert!(to_csv(&reports, &[]).ends_with("2022,15,2,error,,50\n"));
+    }
+
+    #[test]
+    fn test_total_elapsed() {
+        assert_eq!(total_elapsed(&reports()), Duration::from_nanos(12_800));
+    }
+
+    #[test]
+    fn test_parsed() {
+        let mut reports = reports();
+        reports[2].part = PARSE;
+        assert_eq!(reports[2].status(), Status::Parsed);
+        assert_eq!(total_elapsed(&reports), Duration::from_nanos(12_850));
+        assert!(to_csv(&reports, &[]).ends_with("2022,15,0,parsed,,50\n"));
+    }
+
+    #[test]
+    fn test_skipped() {
+        let skipped = [
+            Skipped {
+                year: 2022,
+                day: 7,
+                reason: SkipReason::MissingInput,
+            },
+            Skipped {
+                year: 2022,
+                day: 25,
+                reason: SkipReason::Unregistered,
+            },
+        ];
+
+        let json: serde_json::Value = serde_json::from_str(&to_json(&reports(), &skipped)).unwrap();
+        assert_eq!(
+            json[1],
+            serde_json::json!({"year": 2022, "day": 7, "part": 1, "status": "skipped", "answer": null, "reason": "missing_input", "elapsed_ns": null})
+        );
+        assert_eq!(json[2]["part"], 2);
+        assert_eq!(json[3]["day"], 10);
+        assert_eq!(json[6]["reason"], "unregistered");
+
+        let csv = to_csv(&reports(), &skipped);
+        assert!(csv.contains("2022,5,1,solved,CMZ,12000\n2022,7,1,skipped,,\n2022,7,2,skipped,,\n"));
+        assert!(csv.ends_with("2022,25,2,skipped,,\n"));
+    }
+}
@@ -0,0 +1,331 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use crate::answers::{Answers, Verdict};
+use crate::baseline::Baseline;
+use crate::report::{self, PartReport, Status};
+
+/// Whether an input or example file is there and has content.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileState {
+    Missing,
+    Empty,
+    Present,
+}
+
+impl FileState {
+    pub fn of(path: &Path) -> Self {
+        match fs::read_to_string(path) {
+            Ok(contents) if contents.trim().is_empty() => FileState::Empty,
+            Ok(_) => FileState::Present,
+            Err(_) => FileState::Missing,
+        }
+    }
+
+    fn label(self) -> &'static str {
+        match self {
+            FileState::Missing => "no",
+            FileState::Empty => "empty",
+            FileState::Present => "yes",
+        }
+    }
+}
+
+/// What `status` finds on disk for one day.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayFiles {
+    /// The source of `src/yYYYY/days/NN.rs`, if it exists.
+    pub module: Option<String>,
+    pub input: FileState,
+    pub example: FileState,
+}
+
+impl DayFiles {
+    pub fn probe(year: u16, day: u8) -> Self {
+        let dir = crate::year_dir(year);
+        let file = |folder| FileState::of(&dir.join(folder).join(format!("{:02}.txt", day)));
+        DayFiles {
+            module: fs::read_to_string(dir.join("days").join(format!("{:02}.rs", day))).ok(),
+            input: file("inputs"),
+            example: file("examples"),
+        }
+    }
+}
+
+/// Which parts have a test in `source` asserting an answer, e.g.
+/// `assert_eq!(part_one(&input), Ok(Some(24000)))`. Freshly scaffolded days assert `Ok(None)`.
+pub fn example_answers(source: &str) -> [bool; 2] {
+    let tests = source
+        .find("#[cfg(test)]")
+        .map_or("", |start| &source[start..]);
+    let asserts: Vec<&str> = tests
+        .split("assert_eq!(")
+        .skip(1)
+        .map(|rest| rest.split(';').next().unwrap_or(rest))
+        .collect();
+    ["part_one(", "part_two("].map(|part| {
+        asserts
+            .iter()
+            .any(|a| a.contains(part) && a.contains("Some("))
+    })
+}
+
+/// One row of the `status` calendar.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayStatus {
+    pub day: u8,
+    /// Whether `src/yYYYY/days/NN.rs` exists.
+    pub module: bool,
+    pub input: FileState,
+    pub example: FileState,
+    /// Which parts have their example answer recorded in the day's tests.
+    pub example_answers: [bool; 2],
+    /// The status of each part: from the run with `--run`, otherwise `Solved` for parts in the
+    /// baseline. Parts of a panicking day have no status.
+    pub parts: [Option<Status>; 2],
+    /// Whether the answers recorded in `answers.toml` match. Only known with `--run`.
+    pub verified: Option<bool>,
+    /// Combined time of the solved parts, from the run with `--run`, otherwise from the baseline.
+    pub elapsed: Option<Duration>,
+}
+
+impl DayStatus {
+    /// The status of `day` without running it, with its timings from `baseline`.
+    pub fn new(year: u16, day: u8, files: &DayFiles, baseline: &Baseline) -> Self {
+        let timings = [1, 2].map(|part| baseline.get(year, day, part));
+        DayStatus {
+            day,
+            module: files.module.is_some(),
+            input: files.input,
+            example: files.example,
+            example_answers: files.module.as_deref().map_or([false; 2], example_answers),
+            parts: timings.map(|t| t.map(|_| Status::Solved)),
+            verified: None,
+            elapsed: timings.iter().flatten().copied().reduce(|sum, t| sum + t),
+        }
+    }
+
+    /// Takes the parts and timing from `reports` of running the day, and verifies them
+    /// against `answers`. `reports` is empty when the day did not run.
+    pub fn with_run(self, reports: &[PartReport], answers: &Answers) -> Self {
+        let part = |part| reports.iter().find(|r| r.part == part);
+        let verdicts: Vec<Verdict> = [1, 2]
+            .into_iter()
+            .filter(|p| answers.get(self.day, *p).is_some())
+            .filter_map(part)
+            .map(|r| answers.verify(r))
+            .collect();
+
+        DayStatus {
+            parts: [part(1).map(|r| r.status()), part(2).map(|r| r.status())],
+            verified: (!verdicts.is_empty()).then(|| verdicts.iter().all(|v| *v == Verdict::Match)),
+            elapsed: (!reports.is_empty()).then(|| report::total_elapsed(reports)),
+            ..self
+        }
+    }
+}
+
+fn part_label(status: Option<Status>) -> &'static str {
+    match status {
+        Some(Status::Solved) => "solved",
+        Some(Status::Unsolved) => "unsolved",
+        Some(Status::Error) => "error",
+        Some(Status::Parsed | Status::Skipped) | None => "-",
+    }
+}
+
+/// Renders one row per day as an aligned text table.
+pub fn render(rows: &[DayStatus]) -> String {
+    let header = [
+        "Day",
+        "Module",
+        "Input",
+        "Example",
+        "Ex. answers",
+        "Part 1",
+        "Part 2",
+        "Verified",
+        "Time",
+    ];
+    let cells: Vec<[String; 9]> = rows
+        .iter()
+        .map(|row| {
+            [
+                format!("{:02}", row.day),
+                if row.module { "yes" } else { "no" }.to_owned(),
+                row.input.label().to_owned(),
+                row.example.label().to_owned(),
+                format!("{}/2", row.example_answers.iter().filter(|a| **a).count()),
+                part_label(row.parts[0]).to_owned(),
+                part_label(row.parts[1]).to_owned(),
+                match row.verified {
+                    Some(true) => "match",
+                    Some(false) => "MISMATCH",
+                    None => "-",
+                }
+                .to_owned(),
+                row.elapsed
+                    .map_or_else(|| "-".to_owned(), |elapsed| format!("{:.2?}", elapsed)),
+            ]
+        })
+        .collect();
+
+    let widths: Vec<usize> = (0..header.len())
+        .map(|i| {
+            cells
+                .iter()
+                .map(|row| row[i].chars().count())
+                .chain([header[i].len()])
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let line = |values: Vec<&str>| {
+        let padded: Vec<String> = values
+            .iter()
+            .zip(&widths)
+            .map(|(value, width)| format!("{:<width$}", value, width = width))
+            .collect();
+        padded.join("  ").trim_end().to_owned() + "\n"
+    };
+
+    let mut table = line(header.to_vec());
+    for row in &cells {
+        table.push_str(&line(row.iter().map(String::as_str).collect()));
+    }
+    table
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::baseline::BaselineEntry;
+
+    const MODULE: &str = r#"
+pub fn part_one(input: &str) -> Result<Option<u32>, Error> { Ok(None) }
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn test_part_one() {
+        assert_eq!(part_one(&input), Ok(Some(24000)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        assert_eq!(part_two(&input), Ok(None));
+    }
+}
+"#;
+
+    fn report(part: u8, answer: Option<&str>) -> PartReport {
+        PartReport {
+            year: 2022,
+            day: 1,
+            part,
+            answer: answer.map(|a| a.to_owned()),
+            error: None,
+            elapsed: Duration::from_millis(2),
+            bench: None,
+        }
+    }
+
+    fn files(module: Option<&str>) -> DayFiles {
+        DayFiles {
+            module: module.map(|m| m.to_owned()),
+            input: FileState::Present,
+            example: FileState::Empty,
+        }
+    }
+
+    #[test]
+    fn test_example_answers() {
+        assert_eq!(example_answers(MODULE), [true, false]);
+        assert_eq!(example_answers("fn part_one() { Some(1) }"), [false, false]);
+    }
+
+    #[test]
+    fn test_day_status() {
+        let baseline = Baseline {
+            parts: vec![BaselineEntry {
+                year: 2022,
+                day: 1,
+                part: 1,
+                elapsed_ns: 3_000_000,
+            }],
+        };
+
+        let status = DayStatus::new(2022, 1, &files(Some(MODULE)), &baseline);
+        assert!(status.module);
+        assert_eq!(status.example, FileState::Empty);
+        assert_eq!(status.example_answers, [true, false]);
+        assert_eq!(status.parts, [Some(Status::Solved), None]);
+        assert_eq!(status.verified, None);
+        assert_eq!(status.elapsed, Some(Duration::from_millis(3)));
+
+        let status = DayStatus::new(2022, 2, &files(None), &baseline);
+        assert!(!status.module);
+        assert_eq!(status.example_answers, [false, false]);
+        assert_eq!(status.parts, [None, None]);
+        assert_eq!(status.elapsed, None);
+    }
+
+    #[test]
+    fn test_with_run() {
+        let answers = Answers::parse("[01]\npart_one = 24000\n").unwrap();
+        let status = DayStatus::new(2022, 1, &files(Some(MODULE)), &Baseline { parts: vec![] });
+
+        let run = status
+            .clone()
+            .with_run(&[report(1, Some("24000")), report(2, None)], &answers);
+        assert_eq!(run.parts, [Some(Status::Solved), Some(Status::Unsolved)]);
+        assert_eq!(run.verified, Some(true));
+        assert_eq!(run.elapsed, Some(Duration::from_millis(2)));
+
+        let run = status
+            .clone()
+            .with_run(&[report(1, Some("1")), report(2, Some("2"))], &answers);
+        assert_eq!(run.verified, Some(false));
+
+        let run = status.with_run(&[], &answers);
+        assert_eq!(run.parts, [None, None]);
+        assert_eq!(run.verified, None);
+        assert_eq!(run.elapsed, None);
+    }
+
+    #[test]
+    fn test_render() {
+        let row = DayStatus {
+            day: 7,
+            module: true,
+            input: FileState::Present,
+            example: FileState::Empty,
+            example_answers: [true, true],
+            parts: [Some(Status::Solved), Some(Status::Error)],
+            verified: Some(false),
+            elapsed: Some(Duration::from_micros(1500)),
+        };
+        let empty = DayStatus {
+            day: 8,
+            module: false,
+            input: FileState::Missing,
+            example: FileState::Missing,
+            example_answers: [false, false],
+            parts: [None, None],
+            verified: None,
+            elapsed: None,
+        };
+
+        assert_eq!(
+            render(&[row, empty]),
+            "Day  Module  Input  Example  Ex. answers  Part 1  Part 2  Verified  Time\n\
+             07   yes     yes    empty    2/2          solved  error   MISMATCH  1.50ms\n\
+             08   no      no     no       0/2          -       -       -         -\n"
+        );
+    }
+}
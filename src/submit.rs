@@ -0,0 +1,287 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Location of a year's submission log, e.g. `src/y2022/submissions.json`.
+pub fn path(year: u16) -> PathBuf {
+    crate::year_dir(year).join("submissions.json")
+}
+
+/// How the puzzle site responded to a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Correct,
+    Incorrect,
+    TooHigh,
+    TooLow,
+    /// Rate-limited; nothing was checked.
+    Wait {
+        seconds: u64,
+    },
+    /// The part was already solved, or part one is still missing.
+    WrongLevel,
+    Unknown,
+}
+
+impl Outcome {
+    /// Whether the answer was checked and rejected.
+    pub fn is_rejection(&self) -> bool {
+        matches!(
+            self,
+            Outcome::Incorrect | Outcome::TooHigh | Outcome::TooLow
+        )
+    }
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "That's the right answer!"),
+            Outcome::Incorrect => write!(f, "That's not the right answer."),
+            Outcome::TooHigh => write!(f, "That's not the right answer; it is too high."),
+            Outcome::TooLow => write!(f, "That's not the right answer; it is too low."),
+            Outcome::Wait { seconds } => write!(
+                f,
+                "You gave an answer too recently, wait {:?} before trying again.",
+                Duration::from_secs(*seconds)
+            ),
+            Outcome::WrongLevel => write!(
+                f,
+                "You don't seem to be solving the right level. Did you already complete it?"
+            ),
+            Outcome::Unknown => write!(f, "Could not make sense of the response."),
+        }
+    }
+}
+
+/// Reads the outcome from the page returned after posting an answer.
+pub fn parse_response(html: &str) -> Outcome {
+    if html.contains("That's the right answer") {
+        Outcome::Correct
+    } else if html.contains("That's not the right answer") {
+        if html.contains("too high") {
+            Outcome::TooHigh
+        } else if html.contains("too low") {
+            Outcome::TooLow
+        } else {
+            Outcome::Incorrect
+        }
+    } else if html.contains("You gave an answer too recently") {
+        Outcome::Wait {
+            seconds: parse_wait(html).unwrap_or(60),
+        }
+    } else if html.contains("You don't seem to be solving the right level") {
+        Outcome::WrongLevel
+    } else {
+        Outcome::Unknown
+    }
+}
+
+/// Seconds from "You have 1m 23s left to wait".
+fn parse_wait(html: &str) -> Option<u64> {
+    let start = html.find("You have ")? + "You have ".len();
+    let end = start + html[start..].find(" left to wait")?;
+
+    html[start..end]
+        .split_whitespace()
+        .map(|amount| {
+            if let Some(minutes) = amount.strip_suffix('m') {
+                minutes.parse::<u64>().ok().map(|m| m * 60)
+            } else {
+                amount.strip_suffix('s')?.parse::<u64>().ok()
+            }
+        })
+        .sum()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submission {
+    pub day: u8,
+    pub part: u8,
+    pub answer: String,
+    pub outcome: Outcome,
+}
+
+/// Every answer submitted for a year, with the site's response.
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Submissions {
+    pub submissions: Vec<Submission>,
+}
+
+impl Submissions {
+    /// Reads the submission log. A missing file is treated as having no submissions.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => serde_json::from_str(&contents).map_err(io::Error::other),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Submissions::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let contents = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(path, contents + "\n")
+    }
+
+    pub fn record(&mut self, day: u8, part: u8, answer: &str, outcome: Outcome) {
+        self.submissions.push(Submission {
+            day,
+            part,
+            answer: answer.to_owned(),
+            outcome,
+        });
+    }
+
+    /// Checks `answer` against earlier verdicts, returning why it should not be submitted.
+    pub fn check(&self, day: u8, part: u8, answer: &str) -> Result<(), String> {
+        let previous: Vec<&Submission> = self
+            .submissions
+            .iter()
+            .filter(|s| s.day == day && s.part == part)
+            .collect();
+
+        if let Some(correct) = previous.iter().find(|s| s.outcome == Outcome::Correct) {
+            return Err(format!(
+                "this part was already solved with {}.",
+                correct.answer
+            ));
+        }
+        if let Some(rejected) = previous
+            .iter()
+            .find(|s| s.answer == answer && s.outcome.is_rejection())
+        {
+            return Err(format!(
+                "{} was already submitted. {}",
+                answer, rejected.outcome
+            ));
+        }
+
+        let Ok(number) = answer.parse::<i64>() else {
+            return Ok(());
+        };
+        for s in &previous {
+            let Ok(guess) = s.answer.parse::<i64>() else {
+                continue;
+            };
+            match s.outcome {
+                Outcome::TooLow if number <= guess => {
+                    return Err(format!(
+                        "{} is below a previously rejected too-low guess ({}).",
+                        answer, guess
+                    ))
+                }
+                Outcome::TooHigh if number >= guess => {
+                    return Err(format!(
+                        "{} is above a previously rejected too-high guess ({}).",
+                        answer, guess
+                    ))
+                }
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(message: &str) -> String {
+        format!(
+            "<html><body><main><article><p>{}</p></article></main></body></html>",
+            message
+        )
+    }
+
+    #[test]
+    fn test_parse_response() {
+        assert_eq!(
+            parse_response(&page("That's the right answer!  You are <span class=\"day-success\">one gold star</span> closer.")),
+            Outcome::Correct
+        );
+        assert_eq!(
+            parse_response(&page("That's not the right answer.  If you're stuck, ...")),
+            Outcome::Incorrect
+        );
+        assert_eq!(
+            parse_response(&page(
+                "That's not the right answer; your answer is too high."
+            )),
+            Outcome::TooHigh
+        );
+        assert_eq!(
+            parse_response(&page(
+                "That's not the right answer; your answer is too low."
+            )),
+            Outcome::TooLow
+        );
+        assert_eq!(
+            parse_response(&page("You gave an answer too recently; you have to wait after submitting an answer before trying again.  You have 1m 23s left to wait.")),
+            Outcome::Wait { seconds: 83 }
+        );
+        assert_eq!(
+            parse_response(&page(
+                "You don't seem to be solving the right level.  Did you already complete it?"
+            )),
+            Outcome::WrongLevel
+        );
+        assert_eq!(parse_response(&page("Hello")), Outcome::Unknown);
+    }
+
+    #[test]
+    fn test_parse_wait() {
+        assert_eq!(parse_wait("You have 45s left to wait."), Some(45));
+        assert_eq!(parse_wait("You have 2m left to wait."), Some(120));
+        assert_eq!(parse_wait("You have 1m 5é left to wait."), None);
+    }
+
+    #[test]
+    fn test_check() {
+        let mut submissions = Submissions::default();
+        submissions.record(1, 1, "100", Outcome::TooLow);
+        submissions.record(1, 1, "500", Outcome::TooHigh);
+        submissions.record(1, 1, "300", Outcome::Incorrect);
+        submissions.record(1, 1, "301", Outcome::Wait { seconds: 30 });
+
+        assert!(submissions
+            .check(1, 1, "99")
+            .unwrap_err()
+            .contains("too-low"));
+        assert!(submissions.check(1, 1, "500").is_err());
+        assert!(submissions.check(1, 1, "300").is_err());
+        assert_eq!(submissions.check(1, 1, "301"), Ok(()));
+        assert_eq!(submissions.check(1, 1, "CMZ"), Ok(()));
+        assert_eq!(submissions.check(1, 2, "99"), Ok(()));
+
+        submissions.record(1, 1, "250", Outcome::Correct);
+        assert!(submissions.check(1, 1, "250").is_err());
+    }
+
+    #[test]
+    fn test_serialize() {
+        let mut submissions = Submissions::default();
+        submissions.record(1, 2, "45000", Outcome::TooLow);
+        submissions.record(1, 2, "45001", Outcome::Wait { seconds: 60 });
+
+        let json = serde_json::to_string(&submissions).unwrap();
+        assert!(json.contains("\"outcome\":\"too_low\""));
+        assert!(json.contains("\"outcome\":{\"wait\":{\"seconds\":60}}"));
+        assert_eq!(
+            serde_json::from_str::<Submissions>(&json).unwrap(),
+            submissions
+        );
+    }
+}
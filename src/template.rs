@@ -0,0 +1,194 @@
+/*
+ * This file contains template code.
+ * There is no need to edit this file unless you want to change template functionality.
+ */
+use std::fs;
+use std::io::ErrorKind;
+use std::path::Path;
+
+/// Directory holding the templates of `cargo scaffold`, one subdirectory per variant.
+pub const DIR: &str = "templates";
+
+/// The variant used without `--template`. Other variants fall back to its files.
+pub const DEFAULT_VARIANT: &str = "default";
+
+/// Value of `{{title}}` when the puzzle's description hasn't been downloaded.
+pub const UNTITLED: &str = "Untitled";
+
+/// Answer type of both parts when none is given with `--answer`.
+pub const DEFAULT_ANSWER: &str = "u32";
+
+/// The files a new day is generated from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Template {
+    /// `day.rs`, the day's module in `src/yYYYY/days/`.
+    pub module: String,
+    /// `bin.rs`, the day's binary in `src/yYYYY/bin/`.
+    pub bin: String,
+    /// `solution.txt`, the day's entry in the all-days runner in `src/main.rs`.
+    pub solution: String,
+}
+
+impl Template {
+    /// Loads `variant` from `dir`, taking the files it doesn't have from the default variant.
+    pub fn load(dir: &Path, variant: &str) -> Result<Template, String> {
+        if !dir.join(variant).is_dir() {
+            return Err(format!(
+                "unknown template \"{}\", available: {}",
+                variant,
+                variants(dir).join(", ")
+            ));
+        }
+
+        let file = |name: &str| {
+            [variant, DEFAULT_VARIANT]
+                .iter()
+                .map(|v| dir.join(v).join(name))
+                .find_map(|path| match fs::read_to_string(&path) {
+                    Err(e) if e.kind() == ErrorKind::NotFound => None,
+                    result => Some(result.map_err(|e| format!("\"{}\": {}", path.display(), e))),
+                })
+                .unwrap_or_else(|| Err(format!("template \"{}\" has no {}", variant, name)))
+        };
+
+        Ok(Template {
+            module: file("day.rs")?,
+            bin: file("bin.rs")?,
+            solution: file("solution.txt")?,
+        })
+    }
+}
+
+/// Names of the template variants in `dir`, sorted.
+pub fn variants(dir: &Path) -> Vec<String> {
+    let mut names: Vec<String> = fs::read_dir(dir)
+        .into_iter()
+        .flatten()
+        .flatten()
+        .filter(|entry| entry.path().is_dir())
+        .filter_map(|entry| entry.file_name().into_string().ok())
+        .collect();
+    names.sort();
+    names
+}
+
+/// The values of the placeholders in a template.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Placeholders {
+    pub year: u16,
+    pub day: u8,
+    /// The puzzle's title, e.g. `Calorie Counting`.
+    pub title: String,
+    pub answer_one: String,
+    pub answer_two: String,
+}
+
+impl Placeholders {
+    fn get(&self, name: &str) -> Option<String> {
+        match name {
+            "year" => Some(self.year.to_string()),
+            "day" => Some(self.day.to_string()),
+            "day_padded" => Some(format!("{:02}", self.day)),
+            "title" => Some(self.title.clone()),
+            "answer_one" => Some(self.answer_one.clone()),
+            "answer_two" => Some(self.answer_two.clone()),
+            _ => None,
+        }
+    }
+}
+
+/// Parses `--answer T` or `--answer T1,T2` into the answer types of part one and two.
+pub fn answer_types(value: &str) -> Result<(String, String), String> {
+    let types: Vec<&str> = value.split(',').map(str::trim).collect();
+    match types.as_slice() {
+        [both] if !both.is_empty() => Ok((both.to_string(), both.to_string())),
+        [one, two] if !one.is_empty() && !two.is_empty() => Ok((one.to_string(), two.to_string())),
+        _ => Err(format!(
+            "expected one answer type or two separated by a comma, got \"{}\"",
+            value
+        )),
+    }
+}
+
+/// Replaces each `{{name}}` in `template`: `year`, `day`, `day_padded`, `title`,
+/// `answer_one` and `answer_two`. Unknown names are an error.
+pub fn render(template: &str, values: &Placeholders) -> Result<String, String> {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+
+    while let Some(start) = rest.find("{{") {
+        let end = rest[start..]
+            .find("}}")
+            .ok_or_else(|| "unclosed \"{{\" in template".to_owned())?
+            + start;
+        let name = rest[start + 2..end].trim();
+        let value = values
+            .get(name)
+            .ok_or_else(|| format!("unknown placeholder \"{{{{{}}}}}\" in template", name))?;
+        rendered.push_str(&rest[..start]);
+        rendered.push_str(&value);
+        rest = &rest[end + 2..];
+    }
+
+    rendered.push_str(rest);
+    Ok(rendered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn placeholders() -> Placeholders {
+        Placeholders {
+            year: 2022,
+            day: 7,
+            title: "No Space Left On Device".to_owned(),
+            answer_one: "u32".to_owned(),
+            answer_two: "String".to_owned(),
+        }
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(
+            render(
+                "// Day {{day}}: {{ title }}\nday{{day_padded}}, {{year}} -> {{answer_two}}",
+                &placeholders()
+            ),
+            Ok("// Day 7: No Space Left On Device\nday07, 2022 -> String".to_owned())
+        );
+        assert_eq!(
+            render("{{month}}", &placeholders()),
+            Err("unknown placeholder \"{{month}}\" in template".to_owned())
+        );
+        assert!(render("{{day", &placeholders()).is_err());
+    }
+
+    #[test]
+    fn test_answer_types() {
+        assert_eq!(
+            answer_types("u64"),
+            Ok(("u64".to_owned(), "u64".to_owned()))
+        );
+        assert_eq!(
+            answer_types("i64, String"),
+            Ok(("i64".to_owned(), "String".to_owned()))
+        );
+        assert!(answer_types("u32,").is_err());
+    }
+
+    #[test]
+    fn test_variants_render() {
+        let dir = Path::new(DIR);
+        let names = variants(dir);
+        assert!(names.iter().any(|name| name == DEFAULT_VARIANT));
+
+        for name in names {
+            let template = Template::load(dir, &name).unwrap();
+            for file in [&template.module, &template.bin, &template.solution] {
+                assert!(render(file, &placeholders()).is_ok(), "{}", name);
+            }
+        }
+        assert!(Template::load(dir, "missing").is_err());
+    }
+}
@@ -0,0 +1,7 @@
+use advent_of_code::days::y2022::day01::*;
+
+fn main() {
+    let input = &advent_of_code::read_input(2022, 1);
+    advent_of_code::solve!(2022, 1, 1, part_one, input);
+    advent_of_code::solve!(2022, 1, 2, part_two, input);
+}
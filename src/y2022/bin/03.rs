@@ -0,0 +1,7 @@
+use advent_of_code::days::y2022::day03::*;
+
+fn main() {
+    let input = &advent_of_code::read_input(2022, 3);
+    advent_of_code::solve!(2022, 3, 1, part_one, input);
+    advent_of_code::solve!(2022, 3, 2, part_two, input);
+}
@@ -0,0 +1,6 @@
+use advent_of_code::days::y2022::day07::*;
+
+fn main() {
+    let input = &advent_of_code::read_input(2022, 7);
+    advent_of_code::solve!(2022, 7, Day07, input);
+}
@@ -0,0 +1,7 @@
+use advent_of_code::days::y2022::day15::*;
+
+fn main() {
+    let input = &advent_of_code::read_input(2022, 15);
+    advent_of_code::solve!(2022, 15, 1, part_one, input, Area);
+    advent_of_code::solve!(2022, 15, 2, part_two, input, Area);
+}
@@ -0,0 +1,6 @@
+use advent_of_code::days::y2022::day21::*;
+
+fn main() {
+    let input = &advent_of_code::read_input(2022, 21);
+    advent_of_code::solve!(2022, 21, Day21, input);
+}
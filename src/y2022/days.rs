@@ -0,0 +1,25 @@
+//! The solutions of 2022, one module per day.
+#[path = "days/01.rs"]
+pub mod day01;
+#[path = "days/02.rs"]
+pub mod day02;
+#[path = "days/03.rs"]
+pub mod day03;
+#[path = "days/04.rs"]
+pub mod day04;
+#[path = "days/05.rs"]
+pub mod day05;
+#[path = "days/06.rs"]
+pub mod day06;
+#[path = "days/07.rs"]
+pub mod day07;
+#[path = "days/09.rs"]
+pub mod day09;
+#[path = "days/10.rs"]
+pub mod day10;
+#[path = "days/13.rs"]
+pub mod day13;
+#[path = "days/15.rs"]
+pub mod day15;
+#[path = "days/21.rs"]
+pub mod day21;
@@ -0,0 +1,46 @@
+use advent_of_code::Error;
+
+/// Total calories carried by each elf. Elves are separated by empty lines.
+fn calories_per_elf(input: &str) -> Result<Vec<u32>, Error> {
+    let mut sums = vec![0];
+    for line in input.lines() {
+        if line.is_empty() {
+            sums.push(0);
+        } else {
+            let calories: u32 = line
+                .parse()
+                .map_err(|_| Error::parse_at(input, line, "expected a number of calories"))?;
+            if let Some(sum) = sums.last_mut() {
+                *sum += calories;
+            }
+        }
+    }
+    Ok(sums)
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, Error> {
+    Ok(calories_per_elf(input)?.into_iter().max())
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, Error> {
+    let mut sums = calories_per_elf(input)?;
+    sums.sort_unstable();
+    Ok(Some(sums.iter().rev().take(3).sum()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file(2022, "examples", 1);
+        assert_eq!(part_one(&input), Ok(Some(24000)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file(2022, "examples", 1);
+        assert_eq!(part_two(&input), Ok(Some(45000)));
+    }
+}
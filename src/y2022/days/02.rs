@@ -7,16 +7,11 @@ enum Play {
 
 use Play::*;
 
+use advent_of_code::Error;
 use std::str::FromStr;
-#[derive(Debug)]
-enum ParseError {
-    Play,
-    Winner,
-    Pair,
-}
 
 impl FromStr for Play {
-    type Err = ParseError;
+    type Err = &'static str;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "A" => Ok(Rock),
@@ -25,12 +20,12 @@ impl FromStr for Play {
             "X" => Ok(Rock),
             "Y" => Ok(Paper),
             "Z" => Ok(Scissor),
-            _ => Err(ParseError::Play),
+            _ => Err("expected a play of A, B, C, X, Y or Z"),
         }
     }
 }
 
-fn map_line_part1(line: &str) -> Result<(Play, Play), ParseError> {
+fn map_line_part1(line: &str) -> Result<(Play, Play), &'static str> {
     let spl = line.split_once(' ');
     match spl {
         Some((f, l)) => {
@@ -38,7 +33,7 @@ fn map_line_part1(line: &str) -> Result<(Play, Play), ParseError> {
             let f = Play::from_str(f)?;
             Ok((f, l))
         }
-        None => Err(ParseError::Pair),
+        None => Err("expected two space separated values"),
     }
 }
 
@@ -52,13 +47,13 @@ enum Winner {
 use Winner::*;
 
 impl FromStr for Winner {
-    type Err = ParseError;
+    type Err = &'static str;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
             "X" => Ok(First),
             "Y" => Ok(Draw),
             "Z" => Ok(Second),
-            _ => Err(ParseError::Winner),
+            _ => Err("expected an outcome of X, Y or Z"),
         }
     }
 }
@@ -89,15 +84,15 @@ fn score(t: &(Play, Play)) -> u32 {
     pick_points + result_points
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
+pub fn part_one(input: &str) -> Result<Option<u32>, Error> {
     let plays = input
-        .split('\n')
-        .map(|line| map_line_part1(line).expect("mapping line correctly"));
-    let scores = plays.map(|t| score(&t));
-    Some(scores.sum())
+        .lines()
+        .map(|line| map_line_part1(line).map_err(|e| Error::parse_at(input, line, e)));
+    let scores = plays.map(|t| t.map(|t| score(&t)));
+    Ok(Some(scores.sum::<Result<u32, Error>>()?))
 }
 
-fn map_line_part2(line: &str) -> Result<(Play, Winner), ParseError> {
+fn map_line_part2(line: &str) -> Result<(Play, Winner), &'static str> {
     let spl = line.split_once(' ');
     match spl {
         Some((f, l)) => {
@@ -105,7 +100,7 @@ fn map_line_part2(line: &str) -> Result<(Play, Winner), ParseError> {
             let l = Winner::from_str(l)?;
             Ok((f, l))
         }
-        None => Err(ParseError::Pair),
+        None => Err("expected two space separated values"),
     }
 }
 
@@ -123,20 +118,12 @@ fn make_plays(t: &(Play, Winner)) -> (Play, Play) {
     }
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &str) -> Result<Option<u32>, Error> {
     let plays_with_winners = input
-        .split('\n')
-        .map(|line| map_line_part2(line).expect("mapping line correctly"));
-    let scores = plays_with_winners
-        .map(|t| make_plays(&t))
-        .map(|t| score(&t));
-    Some(scores.sum())
-}
-
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 2);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+        .lines()
+        .map(|line| map_line_part2(line).map_err(|e| Error::parse_at(input, line, e)));
+    let scores = plays_with_winners.map(|t| t.map(|t| score(&make_plays(&t))));
+    Ok(Some(scores.sum::<Result<u32, Error>>()?))
 }
 
 #[cfg(test)]
@@ -145,13 +132,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 2);
-        assert_eq!(part_one(&input), Some(15));
+        let input = advent_of_code::read_file(2022, "examples", 2);
+        assert_eq!(part_one(&input), Ok(Some(15)));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 2);
-        assert_eq!(part_two(&input), Some(12));
+        let input = advent_of_code::read_file(2022, "examples", 2);
+        assert_eq!(part_two(&input), Ok(Some(12)));
     }
 }
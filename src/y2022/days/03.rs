@@ -1,13 +1,14 @@
 fn score(c: char) -> u32 {
-    if ('a'..='z').contains(&c) {
+    if c.is_ascii_lowercase() {
         return c as u32 - 96;
     }
-    if ('A'..='Z').contains(&c) {
+    if c.is_ascii_uppercase() {
         return c as u32 - 64 + 26;
     }
     0
 }
 
+use advent_of_code::Error;
 use std::collections::HashSet;
 
 fn str_as_hashset(s: &str) -> HashSet<char> {
@@ -33,16 +34,18 @@ pub fn split_in_half(input: &str) -> (&str, &str) {
     (first, secnd)
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let lines = input.split('\n');
-    let splits = lines.map(|line| split_in_half(&line));
-    let overlaps = splits.map(|(s1, s2)| overlaps(s1, s2).expect("should have overlap"));
-    let scores = overlaps.map(score);
-    Some(scores.sum())
+pub fn part_one(input: &str) -> Result<Option<u32>, Error> {
+    let overlaps = input.lines().map(|line| {
+        let (s1, s2) = split_in_half(line);
+        overlaps(s1, s2)
+            .ok_or_else(|| Error::parse_at(input, line, "expected an item in both compartments"))
+    });
+    let scores = overlaps.map(|c| c.map(score));
+    Ok(Some(scores.sum::<Result<u32, Error>>()?))
 }
 
 use itertools::Itertools;
-pub fn part_two(input: &str) -> Option<u32> {
+pub fn part_two(input: &str) -> Result<Option<u32>, Error> {
     let scores = input
         .lines()
         .tuples()
@@ -52,16 +55,12 @@ pub fn part_two(input: &str) -> Option<u32> {
             let c_set = str_as_hashset(c);
             let overlap1: HashSet<char> = a_set.intersection(&b_set).copied().collect();
             let mut overlap2 = overlap1.intersection(&c_set);
-            overlap2.next().copied().expect("should have one overlap")
+            overlap2.next().copied().ok_or_else(|| {
+                Error::parse_at(input, a, "expected an item shared by the group of three")
+            })
         })
-        .map(score);
-    Some(scores.sum())
-}
-
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 3);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+        .map(|c| c.map(score));
+    Ok(Some(scores.sum::<Result<u32, Error>>()?))
 }
 
 #[cfg(test)]
@@ -90,13 +89,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 3);
-        assert_eq!(part_one(&input), Some(157));
+        let input = advent_of_code::read_file(2022, "examples", 3);
+        assert_eq!(part_one(&input), Ok(Some(157)));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 3);
-        assert_eq!(part_two(&input), Some(70));
+        let input = advent_of_code::read_file(2022, "examples", 3);
+        assert_eq!(part_two(&input), Ok(Some(70)));
     }
 }
@@ -4,78 +4,67 @@ struct WorkAssignment {
 }
 
 impl WorkAssignment {
+    fn sections(&self) -> RangeInclusive<u32> {
+        self.start..=self.end
+    }
     pub fn fully_overlaps_either(&self, other: &Self) -> bool {
-        if self.start <= other.start && self.end >= other.end {
-            return true;
-        }
-        if other.start <= self.start && other.end >= self.end {
-            return true;
-        }
-        false
+        ranges::contains_range(&self.sections(), &other.sections())
+            || ranges::contains_range(&other.sections(), &self.sections())
     }
     pub fn overlaps_either(&self, other: &Self) -> bool {
-        if self.start <= other.start && self.end >= other.start {
-            return true;
-        }
-        if other.start <= self.start && other.end >= self.start {
-            return true;
-        }
-        false
+        ranges::overlaps(&self.sections(), &other.sections())
     }
 }
 
-use std::str::FromStr;
-#[derive(Debug)]
-enum ParseError {
-    Split,
-    NotInt,
-    PairSplit,
-}
+use advent_of_code::{helpers::ranges, Error};
+use std::{ops::RangeInclusive, str::FromStr};
 
 impl FromStr for WorkAssignment {
-    type Err = ParseError;
+    type Err = &'static str;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let (start, end) = s.split_once('-').ok_or(ParseError::Split)?;
-        let start = start.parse().map_err(|_| ParseError::NotInt)?;
-        let end = end.parse().map_err(|_| ParseError::NotInt)?;
+        let (start, end) = s.split_once('-').ok_or("expected a range like 2-4")?;
+        let start = start.parse().map_err(|_| "expected a number")?;
+        let end = end.parse().map_err(|_| "expected a number")?;
         Ok(WorkAssignment { start, end })
     }
 }
 
-fn parse_line_as_pair(line: &str) -> Result<(WorkAssignment, WorkAssignment), ParseError> {
-    let (fst, snd) = line.split_once(',').ok_or(ParseError::PairSplit)?;
+fn parse_line_as_pair(line: &str) -> Result<(WorkAssignment, WorkAssignment), &'static str> {
+    let (fst, snd) = line
+        .split_once(',')
+        .ok_or("expected two comma separated ranges")?;
     let fst = WorkAssignment::from_str(fst)?;
     let snd = WorkAssignment::from_str(snd)?;
     Ok((fst, snd))
 }
 
-fn part_one(input: &str) -> Option<u32> {
-    Some(
-        input
-            .lines()
-            .map(|line| parse_line_as_pair(line).expect("should be parseable"))
+fn parse_pairs(input: &str) -> Result<Vec<(WorkAssignment, WorkAssignment)>, Error> {
+    input
+        .lines()
+        .map(|line| parse_line_as_pair(line).map_err(|e| Error::parse_at(input, line, e)))
+        .collect()
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, Error> {
+    Ok(Some(
+        parse_pairs(input)?
+            .iter()
             .filter(|(w1, w2)| w1.fully_overlaps_either(w2))
             .count() as u32,
-    )
+    ))
 }
 
-pub fn part_two(input: &str) -> Option<u32> {
-    Some(
-        input
-            .lines()
-            .map(|line| parse_line_as_pair(line).expect("should be parseable"))
+pub fn part_two(input: &str) -> Result<Option<u32>, Error> {
+    Ok(Some(
+        parse_pairs(input)?
+            .iter()
             .filter(|(w1, w2)| w1.overlaps_either(w2))
             .count() as u32,
-    )
-}
-
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 4);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    ))
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
 
@@ -129,13 +118,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 4);
-        assert_eq!(part_one(&input), Some(2));
+        let input = advent_of_code::read_file(2022, "examples", 4);
+        assert_eq!(part_one(&input), Ok(Some(2)));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 4);
-        assert_eq!(part_two(&input), Some(4));
+        let input = advent_of_code::read_file(2022, "examples", 4);
+        assert_eq!(part_two(&input), Ok(Some(4)));
     }
 }
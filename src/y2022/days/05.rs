@@ -1,9 +1,13 @@
+use advent_of_code::{
+    helpers::parse::{lines, parse_all},
+    Error,
+};
 use nom::{
     branch::alt,
     bytes::complete::tag,
     character::complete::{self, anychar, digit1, multispace1, newline, space1},
-    multi::{count, many1, separated_list1},
-    sequence::{pair, preceded, terminated},
+    multi::{many1, separated_list1},
+    sequence::{pair, preceded},
     IResult,
 };
 
@@ -62,18 +66,15 @@ fn parse_single_move(input: &str) -> IResult<&str, Move> {
 }
 
 fn parse_moves(input: &str) -> IResult<&str, Vec<Move>> {
-    separated_list1(newline, parse_single_move)(input)
+    lines(parse_single_move)(input)
 }
 
 fn parse_puzzle(input: &str) -> IResult<&str, (Vec<SupplyStack>, Vec<Move>)> {
     pair(parse_crates, parse_moves)(input)
 }
 
-pub fn part_one(input: &str) -> Option<String> {
-    let (rest, (mut stacks, moves)) = parse_puzzle(input).expect("parses correctly");
-    if !rest.is_empty() {
-        panic!("got remainder in parse: {}", rest)
-    }
+pub fn part_one(input: &str) -> Result<Option<String>, Error> {
+    let (mut stacks, moves) = parse_all(input, parse_puzzle)?;
 
     for mov in moves {
         let from_index = (mov.from - 1) as usize;
@@ -90,14 +91,11 @@ pub fn part_one(input: &str) -> Option<String> {
 
     let result: String = stacks.iter_mut().filter_map(|s| s.pop()).collect();
 
-    Some(result)
+    Ok(Some(result))
 }
 
-pub fn part_two(input: &str) -> Option<String> {
-    let (rest, (mut stacks, moves)) = parse_puzzle(input).expect("parses correctly");
-    if !rest.is_empty() {
-        panic!("got remainder in parse: {}", rest)
-    }
+pub fn part_two(input: &str) -> Result<Option<String>, Error> {
+    let (mut stacks, moves) = parse_all(input, parse_puzzle)?;
 
     for mov in moves {
         let from_index = (mov.from - 1) as usize;
@@ -114,13 +112,7 @@ pub fn part_two(input: &str) -> Option<String> {
 
     let result: String = stacks.iter_mut().filter_map(|s| s.pop()).collect();
 
-    Some(result)
-}
-
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 5);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    Ok(Some(result))
 }
 
 #[cfg(test)]
@@ -131,7 +123,7 @@ mod tests {
 
     #[test]
     fn test_parse_crates() {
-        let input = advent_of_code::read_file("examples", 5)
+        let input = advent_of_code::read_file(2022, "examples", 5)
             .lines()
             .take(5)
             .join("\n");
@@ -144,7 +136,7 @@ mod tests {
 
     #[test]
     fn test_parse_moves() {
-        let input = advent_of_code::read_file("examples", 5)
+        let input = advent_of_code::read_file(2022, "examples", 5)
             .lines()
             .skip(5)
             .join("\n");
@@ -179,15 +171,25 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_bad_move() {
+        let input = advent_of_code::read_file(2022, "examples", 5)
+            .replace("move 3 from 1 to 3", "move 3 frm 1 to 3");
+        assert_eq!(
+            part_one(&input).unwrap_err().to_string(),
+            "parse error at line 7, column 7: expected Tag\n7 | move 3 frm 1 to 3\n  |       ^"
+        );
+    }
+
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 5);
-        assert_eq!(part_one(&input), Some("CMZ".to_owned()));
+        let input = advent_of_code::read_file(2022, "examples", 5);
+        assert_eq!(part_one(&input), Ok(Some("CMZ".to_owned())));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 5);
-        assert_eq!(part_two(&input), Some("MCD".to_owned()));
+        let input = advent_of_code::read_file(2022, "examples", 5);
+        assert_eq!(part_two(&input), Ok(Some("MCD".to_owned())));
     }
 }
@@ -1,5 +1,6 @@
-use std::{collections::HashMap, str::FromStr};
+use std::collections::HashMap;
 
+use advent_of_code::{helpers::parse::parse_all, Day, Error};
 use camino::Utf8PathBuf;
 use itertools::Itertools;
 use nom::{
@@ -14,7 +15,11 @@ use nom::{
 #[derive(Debug, Clone)]
 enum Listing {
     Dir(String),
-    File { size: u32, name: String },
+    File {
+        size: u32,
+        #[allow(dead_code)]
+        name: String,
+    },
 }
 
 #[derive(Debug)]
@@ -72,7 +77,7 @@ struct Directory {
     local_dir_size: u32,
 }
 
-fn calc_local_dir_size(listings: &Vec<Listing>) -> u32 {
+fn calc_local_dir_size(listings: &[Listing]) -> u32 {
     listings
         .iter()
         .map(|l| match l {
@@ -94,11 +99,11 @@ fn run_part_one(commands: Vec<Command>) -> Vec<Directory> {
                     listings,
                     local_dir_size,
                 };
-                dbg!(&dir);
+                advent_of_code::trace!("{:?}", dir);
                 result.push(dir);
             }
             Command::Cd(dir) => {
-                dbg!(&dir);
+                advent_of_code::trace!("cd {}", dir);
                 match dir.as_str() {
                     ".." => {
                         cwd.pop();
@@ -107,7 +112,7 @@ fn run_part_one(commands: Vec<Command>) -> Vec<Directory> {
                         cwd.push(p);
                     }
                 };
-                dbg!(&cwd);
+                advent_of_code::trace!("cwd: {}", cwd);
             }
         }
     }
@@ -118,7 +123,7 @@ fn calc_combined(
     dir: &Directory,
     fs: &HashMap<Utf8PathBuf, Directory>,
     calced: &HashMap<Utf8PathBuf, u32>,
-) -> u32 {
+) -> Result<u32, Error> {
     let subdir_size: u32 = dir
         .listings
         .iter()
@@ -126,16 +131,24 @@ fn calc_combined(
             Listing::Dir(s) => {
                 let mut next_dir_path = dir.path.clone();
                 next_dir_path.push(s);
-                let next_dir = fs.get(&next_dir_path).expect("can find dir in fs");
-                Some(calc_combined(next_dir, fs, calced))
+                if let Some(size) = calced.get(&next_dir_path) {
+                    return Some(Ok(*size));
+                }
+                Some(match fs.get(&next_dir_path) {
+                    Some(next_dir) => calc_combined(next_dir, fs, calced),
+                    None => Err(Error::unsolvable(format!(
+                        "directory {} is never listed",
+                        next_dir_path
+                    ))),
+                })
             }
             _ => None,
         })
-        .sum();
-    dir.local_dir_size + subdir_size
+        .sum::<Result<u32, Error>>()?;
+    Ok(dir.local_dir_size + subdir_size)
 }
 
-fn calc_combined_sizes(dirs: Vec<Directory>) -> HashMap<Utf8PathBuf, u32> {
+fn calc_combined_sizes(dirs: Vec<Directory>) -> Result<HashMap<Utf8PathBuf, u32>, Error> {
     let fs: HashMap<Utf8PathBuf, Directory> =
         dirs.iter().map(|d| (d.path.clone(), d.clone())).collect();
     let mut result: HashMap<Utf8PathBuf, u32> = HashMap::new();
@@ -145,67 +158,60 @@ fn calc_combined_sizes(dirs: Vec<Directory>) -> HashMap<Utf8PathBuf, u32> {
         .sorted_by_key(|d| d.path.ancestors().count())
         .rev()
     {
-        let res = calc_combined(d, &fs, &result);
+        let res = calc_combined(d, &fs, &result)?;
         result.insert(d.path.clone(), res);
     }
 
-    result
+    Ok(result)
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let (input, commands) = parse_input(input).expect("parsed correctly");
-    if !input.is_empty() {
-        panic!("did not fully parse, remaining: {}", input);
-    }
-    let result = run_part_one(commands);
-
-    let combined = calc_combined_sizes(result);
+pub struct Day07;
 
-    Some(
-        combined
-            .iter()
-            .filter_map(|(_p, size)| if size < &100_000 { Some(*size) } else { None })
-            .sum(),
-    )
-}
+impl Day for Day07 {
+    /// The combined size of every directory, by path.
+    type Input<'a> = HashMap<Utf8PathBuf, u32>;
+    type PartOne = u32;
+    type PartTwo = u32;
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let (input, commands) = parse_input(input).expect("parsed correctly");
-    if !input.is_empty() {
-        panic!("did not fully parse, remaining: {}", input);
+    fn parse(input: &str) -> Result<Self::Input<'_>, Error> {
+        let commands = parse_all(input, parse_input)?;
+        calc_combined_sizes(run_part_one(commands))
     }
-    let result = run_part_one(commands);
 
-    let combined = calc_combined_sizes(result);
+    fn part_one(combined: &Self::Input<'_>) -> Result<Option<u32>, Error> {
+        Ok(Some(
+            combined
+                .values()
+                .filter_map(|size| if size < &100_000 { Some(*size) } else { None })
+                .sum(),
+        ))
+    }
 
-    const DISK_SIZE: u32 = 70_000_000;
-    const UPDATE_SIZE: u32 = 30_000_000;
+    fn part_two(combined: &Self::Input<'_>) -> Result<Option<u32>, Error> {
+        const DISK_SIZE: u32 = 70_000_000;
+        const UPDATE_SIZE: u32 = 30_000_000;
 
-    let root_dir = Utf8PathBuf::from_str("/").expect("root is path");
-    let used: u32 = combined
-        .iter()
-        .filter_map(|(path, size)| if path == &root_dir { Some(size) } else { None })
-        .sum();
-    let free = DISK_SIZE - used;
-    let needed = UPDATE_SIZE - free;
-    dbg!((used, free, needed));
+        let root_dir = Utf8PathBuf::from("/");
+        let used: u32 = combined
+            .iter()
+            .filter_map(|(path, size)| if path == &root_dir { Some(size) } else { None })
+            .sum();
+        let free = DISK_SIZE
+            .checked_sub(used)
+            .ok_or_else(|| Error::unsolvable("the files take up more than the whole disk"))?;
+        let needed = UPDATE_SIZE
+            .checked_sub(free)
+            .ok_or_else(|| Error::unsolvable("there is enough free space already"))?;
+        advent_of_code::debug!("used: {}, free: {}, needed: {}", used, free, needed);
 
-    Some(
         combined
             .iter()
             .filter(|(_p, size)| *size > &needed)
             .sorted_by_key(|(_, size)| **size)
             .next()
-            .expect("should have one mathing criteria")
-            .1
-            .to_owned(),
-    )
-}
-
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 7);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+            .map(|(_, size)| Some(*size))
+            .ok_or_else(|| Error::unsolvable("no directory frees up enough space"))
+    }
 }
 
 #[cfg(test)]
@@ -215,14 +221,14 @@ mod tests {
     #[test]
     fn test_cd() {
         let input = "$ cd /\n";
-        println!("input: '{}'", &input);
+        println!("input: '{}'", input);
         match parse_cd(input) {
             Ok((input, parsed)) => {
                 assert_eq!(input.len(), 0);
-                dbg!(parsed);
+                advent_of_code::debug!("{:?}", parsed);
             }
             Err(e) => {
-                dbg!(e);
+                advent_of_code::debug!("{:?}", e);
             }
         }
     }
@@ -230,14 +236,14 @@ mod tests {
     #[test]
     fn test_file() {
         let input = "12345 luis.txt";
-        println!("input: '{}'", &input);
+        println!("input: '{}'", input);
         match parse_file(input) {
             Ok((input, parsed)) => {
                 assert_eq!(input.len(), 0);
-                dbg!(parsed);
+                advent_of_code::debug!("{:?}", parsed);
             }
             Err(e) => {
-                dbg!(e);
+                advent_of_code::debug!("{:?}", e);
             }
         }
     }
@@ -245,42 +251,54 @@ mod tests {
     #[test]
     fn test_ls() {
         let input = "$ ls\ndir a\n12345 luis.txt";
-        println!("input: '{}'", &input);
+        println!("input: '{}'", input);
         match parse_ls(input) {
             Ok((input, parsed)) => {
                 assert_eq!(input.len(), 0);
-                dbg!(parsed);
+                advent_of_code::debug!("{:?}", parsed);
             }
             Err(e) => {
-                dbg!(e);
+                advent_of_code::debug!("{:?}", e);
             }
         }
     }
 
     #[test]
     fn test_parser() {
-        let input = advent_of_code::read_file("examples", 7);
+        let input = advent_of_code::read_file(2022, "examples", 7);
         match parse_input(&input) {
             Ok((input, parsed)) => {
-                dbg!(input);
                 assert_eq!(input.len(), 0);
-                dbg!(parsed);
+                advent_of_code::debug!("{:?}", parsed);
             }
             Err(e) => {
-                dbg!(e);
+                advent_of_code::debug!("{:?}", e);
             }
         }
     }
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 7);
-        assert_eq!(part_one(&input), Some(95437));
+        let input = Day07::parse(&advent_of_code::read_file(2022, "examples", 7)).unwrap();
+        assert_eq!(Day07::part_one(&input), Ok(Some(95437)));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 7);
-        assert_eq!(part_two(&input), Some(24933642));
+        let input = Day07::parse(&advent_of_code::read_file(2022, "examples", 7)).unwrap();
+        assert_eq!(Day07::part_two(&input), Ok(Some(24933642)));
+    }
+
+    #[test]
+    fn test_bad_input() {
+        assert_eq!(
+            Day07::parse("$ cd /\n$ ls\ndir a\n"),
+            Err(Error::unsolvable("directory /a is never listed"))
+        );
+
+        let full = HashMap::from([(Utf8PathBuf::from("/"), 80_000_000)]);
+        assert!(Day07::part_two(&full).is_err());
+        let empty = HashMap::from([(Utf8PathBuf::from("/"), 100)]);
+        assert!(Day07::part_two(&empty).is_err());
     }
 }
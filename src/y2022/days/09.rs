@@ -0,0 +1,203 @@
+use advent_of_code::{
+    helpers::{
+        grid::{Direction, Point},
+        parse::{lines, parse_all},
+    },
+    Error,
+};
+use itertools::Itertools;
+use nom::{
+    character::complete::{self, anychar, space1},
+    combinator::map_opt,
+    IResult,
+};
+
+#[derive(Debug)]
+struct Motion {
+    dir: Direction,
+    amount: u32,
+}
+
+fn parse_motion(input: &str) -> IResult<&str, Motion> {
+    let (input, dir) = map_opt(anychar, Direction::from_char)(input)?;
+    let (input, _) = space1(input)?;
+    let (input, amount) = complete::u32(input)?;
+    Ok((input, Motion { dir, amount }))
+}
+
+fn parse_motions(input: &str) -> IResult<&str, Vec<Motion>> {
+    lines(parse_motion)(input)
+}
+
+fn head_moves(motion: &Motion) -> Vec<Direction> {
+    vec![motion.dir; motion.amount as usize]
+}
+
+/// Moves a knot of the rope one step towards the knot ahead of it, unless they still touch.
+pub fn tail_move(tail: &mut Point<i32>, head: &Point<i32>) {
+    if tail.chebyshev(*head) > 1 {
+        tail.x += (head.x - tail.x).signum();
+        tail.y += (head.y - tail.y).signum();
+    }
+}
+
+pub fn part_one(input: &str) -> Result<Option<u32>, Error> {
+    let motions = parse_all(input, parse_motions)?;
+    let mut tail_visits = Vec::new();
+    let mut tail = Point::new(0, 0);
+    let mut head = Point::new(0, 0);
+    tail_visits.push(tail);
+
+    for motion in motions {
+        for head_move in head_moves(&motion) {
+            head += head_move;
+
+            tail_move(&mut tail, &head);
+            tail_visits.push(tail);
+        }
+    }
+
+    let visited = tail_visits.iter().unique().count();
+
+    Ok(Some(visited as u32))
+}
+
+pub fn part_two(input: &str) -> Result<Option<u32>, Error> {
+    let motions = parse_all(input, parse_motions)?;
+    let mut last_knot_visits: Vec<Point<i32>> = Vec::new();
+    let mut knots = [Point::new(0, 0); 10];
+
+    for motion in motions {
+        for head_move in head_moves(&motion) {
+            knots[0] += head_move;
+
+            let len = knots.len();
+            for index in 0..len - 1 {
+                let head = knots[index];
+                let tail = &mut knots[index + 1];
+                tail_move(tail, &head);
+            }
+
+            last_knot_visits.push(knots[knots.len() - 1]);
+        }
+    }
+
+    let visited = last_knot_visits.iter().unique().count();
+
+    Ok(Some(visited as u32))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tail_move_horizontal() {
+        let mut tail = Point::new(0, 0);
+        let head = Point::new(2, 0);
+        tail_move(&mut tail, &head);
+        assert_eq!(tail, Point::new(1, 0));
+    }
+
+    #[test]
+    fn test_tail_move_horizontal_not_diagonal() {
+        let mut tail = Point::new(3, 0);
+        let head = Point::new(4, 1);
+        tail_move(&mut tail, &head);
+        assert_eq!(tail, Point::new(3, 0));
+    }
+
+    #[test]
+    fn test_tail_move_diagonal_2() {
+        let mut tail = Point::new(2, 3);
+        let head = Point::new(4, 3);
+        tail_move(&mut tail, &head);
+        assert_eq!(tail, Point::new(3, 3));
+    }
+
+    #[test]
+    fn test_tail_move_diagonal_3() {
+        let mut tail = Point::new(4, 3);
+        let head = Point::new(2, 4);
+        tail_move(&mut tail, &head);
+        assert_eq!(tail, Point::new(3, 4));
+    }
+
+    #[test]
+    fn test_tail_move_vertical() {
+        let mut tail = Point::new(0, 0);
+        let head = Point::new(0, 2);
+        tail_move(&mut tail, &head);
+        assert_eq!(tail, Point::new(0, 1));
+    }
+
+    #[test]
+    fn test_tail_move_diagonal() {
+        let mut tail = Point::new(0, 0);
+        let head = Point::new(1, 2);
+        tail_move(&mut tail, &head);
+        assert_eq!(tail, Point::new(1, 1));
+    }
+
+    #[test]
+    fn test_tail_move_nothing() {
+        let mut tail = Point::new(0, 0);
+        let head = Point::new(1, 0);
+        tail_move(&mut tail, &head);
+        assert_eq!(tail, Point::new(0, 0));
+    }
+
+    #[test]
+    fn test_tail_move_nothing_2() {
+        let mut tail = Point::new(2, 4);
+        let head = Point::new(1, 3);
+        tail_move(&mut tail, &head);
+        assert_eq!(tail, Point::new(2, 4));
+    }
+
+    #[test]
+    fn test_tail_move_nothing_3() {
+        let mut tail = Point::new(2, 4);
+        let head = Point::new(2, 3);
+        tail_move(&mut tail, &head);
+        assert_eq!(tail, Point::new(2, 4));
+    }
+
+    #[test]
+    fn test_tail_move_nothing_4() {
+        let mut tail = Point::new(2, 4);
+        let head = Point::new(3, 3);
+        tail_move(&mut tail, &head);
+        assert_eq!(tail, Point::new(2, 4));
+    }
+
+    #[test]
+    fn test_head_moves() {
+        assert_eq!(
+            head_moves(&Motion {
+                dir: Direction::Right,
+                amount: 4
+            }),
+            vec![Direction::Right; 4]
+        );
+    }
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file(2022, "examples", 9);
+        assert_eq!(part_one(&input), Ok(Some(13)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = r#"R 5
+U 8
+L 8
+D 3
+R 17
+D 10
+L 25
+U 20"#;
+        assert_eq!(part_two(input), Ok(Some(36)));
+    }
+}
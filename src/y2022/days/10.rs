@@ -1,12 +1,10 @@
+use advent_of_code::{
+    helpers::parse::{lines, parse_all, signed},
+    Error,
+};
 use std::collections::HashSet;
 
-use nom::{
-    branch::alt,
-    bytes::complete::tag,
-    character::complete::{self, newline},
-    multi::separated_list1,
-    IResult,
-};
+use nom::{branch::alt, bytes::complete::tag, IResult};
 
 #[derive(Debug)]
 enum Instruction {
@@ -21,20 +19,17 @@ fn parse_noop(input: &str) -> IResult<&str, Instruction> {
 }
 fn parse_addx(input: &str) -> IResult<&str, Instruction> {
     let (input, _) = tag("addx ")(input)?;
-    let (input, num) = complete::i32(input)?;
+    let (input, num) = signed(input)?;
 
     Ok((input, Instruction::AddX(num)))
 }
 
 fn parse_program(input: &str) -> IResult<&str, Vec<Instruction>> {
-    separated_list1(newline, alt((parse_noop, parse_addx)))(input)
+    lines(alt((parse_noop, parse_addx)))(input)
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let (remaining, program) = parse_program(input).expect("parses");
-    if !remaining.is_empty() {
-        panic!("did not fully parse, remaining: {}", input);
-    }
+pub fn part_one(input: &str) -> Result<Option<u32>, Error> {
+    let program = parse_all(input, parse_program)?;
     let mut reg_x: i32 = 1;
     let mut cycle: u32 = 1;
     let mut signal_strengths: Vec<i32> = Vec::new();
@@ -55,8 +50,8 @@ pub fn part_one(input: &str) -> Option<u32> {
         reg_x += reg_x_delta;
     }
 
-    dbg!(&signal_strengths);
-    Some(signal_strengths.iter().sum::<i32>() as u32)
+    advent_of_code::debug!("signal strengths: {:?}", signal_strengths);
+    Ok(Some(signal_strengths.iter().sum::<i32>() as u32))
 }
 
 struct State {
@@ -76,7 +71,7 @@ impl State {
 
     pub fn draw_pixel(&mut self) -> Option<String> {
         let pixel = match self.cycle as i32 - self.reg_x {
-            -1 | 0 | 1 => '#',
+            -1..=1 => '#',
             _ => '.',
         };
         self.current_line.push(pixel);
@@ -92,11 +87,8 @@ impl State {
     }
 }
 
-pub fn part_two(input: &str) -> Option<String> {
-    let (remaining, program) = parse_program(input).expect("parses");
-    if !remaining.is_empty() {
-        panic!("did not fully parse, remaining: {}", input);
-    }
+pub fn part_two(input: &str) -> Result<Option<String>, Error> {
+    let program = parse_all(input, parse_program)?;
     let mut lines: Vec<String> = Vec::new();
     let mut state = State::new();
 
@@ -114,13 +106,7 @@ pub fn part_two(input: &str) -> Option<String> {
         state.reg_x += reg_x_delta;
     }
 
-    Some(lines.join("\n"))
-}
-
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 10);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+    Ok(Some(lines.join("\n")))
 }
 
 #[cfg(test)]
@@ -129,13 +115,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 10);
-        assert_eq!(part_one(&input), Some(13140));
+        let input = advent_of_code::read_file(2022, "examples", 10);
+        assert_eq!(part_one(&input), Ok(Some(13140)));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 10);
+        let input = advent_of_code::read_file(2022, "examples", 10);
         let expected: String = r#"##..##..##..##..##..##..##..##..##..##..
 ###...###...###...###...###...###...###.
 ####....####....####....####....####....
@@ -143,7 +129,6 @@ mod tests {
 ######......######......######......####
 #######.......#######.......#######....."#
             .to_owned();
-        dbg!(&expected.lines().collect::<Vec<&str>>());
-        assert_eq!(part_two(&input), Some(expected));
+        assert_eq!(part_two(&input), Ok(Some(expected)));
     }
 }
@@ -1,19 +1,22 @@
 #[derive(Debug, Clone, PartialEq, Eq)]
-enum Packet {
+pub enum Packet {
     Integer(u32),
     List(Vec<Packet>),
 }
 
 use std::cmp;
 
+use advent_of_code::{
+    helpers::parse::{blocks, parse_all},
+    Day, Error,
+};
 use nom::{
     branch::alt,
     character::complete::{self, newline},
     multi::separated_list0,
-    sequence::{delimited, pair, separated_pair},
+    sequence::{delimited, separated_pair},
     IResult,
 };
-use substring::Substring;
 use Packet::*;
 
 fn parse_integer(input: &str) -> IResult<&str, Packet> {
@@ -30,7 +33,8 @@ fn parse_list(input: &str) -> IResult<&str, Packet> {
     Ok((input, List(packets)))
 }
 
-fn parse_packet(input: &str) -> IResult<&str, Packet> {
+/// A single packet, e.g. `[1,[2,[3]]]`.
+pub fn parse_packet(input: &str) -> IResult<&str, Packet> {
     alt((parse_list, parse_integer))(input)
 }
 
@@ -39,7 +43,7 @@ fn parse_packet_pair(input: &str) -> IResult<&str, (Packet, Packet)> {
 }
 
 fn parse_packet_pairs(input: &str) -> IResult<&str, Vec<(Packet, Packet)>> {
-    separated_list0(pair(newline, newline), parse_packet_pair)(input)
+    blocks(parse_packet_pair)(input)
 }
 
 fn are_lists_in_right_order(left: &[Packet], right: &[Packet]) -> Option<bool> {
@@ -72,81 +76,70 @@ fn is_in_right_order(left: &Packet, right: &Packet) -> Option<bool> {
             }
         }
         (List(left), List(right)) => are_lists_in_right_order(left, right),
-        (Integer(_), List(right)) => are_lists_in_right_order(&[left.clone()], right),
-        (List(left), Integer(_)) => are_lists_in_right_order(left, &[right.clone()]),
+        (Integer(_), List(right)) => are_lists_in_right_order(std::slice::from_ref(left), right),
+        (List(left), Integer(_)) => are_lists_in_right_order(left, std::slice::from_ref(right)),
     }
 }
 
-pub fn part_one(input: &str) -> Option<u32> {
-    let (remaining, packets) = parse_packet_pairs(input).expect("parses correctly");
-    if !remaining.is_empty() {
-        panic!(
-            "did not fully parse, remaining: {}...",
-            input.substring(0, 20)
-        );
-    }
+pub struct Day13;
+
+impl Day for Day13 {
+    type Input<'a> = Vec<(Packet, Packet)>;
+    type PartOne = u32;
+    type PartTwo = u32;
 
-    let result: Vec<u32> = packets
-        .iter()
-        .enumerate()
-        .filter(|(_, (left, right))| is_in_right_order(left, right).unwrap_or(true))
-        .map(|(index, _)| (index as u32) + 1)
-        .collect();
+    fn parse(input: &str) -> Result<Self::Input<'_>, Error> {
+        parse_all(input, parse_packet_pairs)
+    }
 
-    dbg!(&result);
+    fn part_one(packets: &Self::Input<'_>) -> Result<Option<u32>, Error> {
+        let result: Vec<u32> = packets
+            .iter()
+            .enumerate()
+            .filter(|(_, (left, right))| is_in_right_order(left, right).unwrap_or(true))
+            .map(|(index, _)| (index as u32) + 1)
+            .collect();
 
-    Some(result.iter().sum())
-}
+        advent_of_code::debug!("pairs in the right order: {:?}", result);
 
-pub fn part_two(input: &str) -> Option<u32> {
-    let (remaining, packets) = parse_packet_pairs(input).expect("parses correctly");
-    if !remaining.is_empty() {
-        panic!(
-            "did not fully parse, remaining: {}...",
-            input.substring(0, 20)
-        );
+        Ok(Some(result.iter().sum()))
     }
 
-    let mut all_packets: Vec<Packet> = packets
-        .into_iter()
-        .flat_map(|(left, right)| [left, right])
-        .collect();
+    fn part_two(packets: &Self::Input<'_>) -> Result<Option<u32>, Error> {
+        let mut all_packets: Vec<Packet> = packets
+            .iter()
+            .flat_map(|(left, right)| [left.clone(), right.clone()])
+            .collect();
 
-    let dist1 = List(vec![List(vec![Integer(2)])]);
-    let dist2 = List(vec![List(vec![Integer(6)])]);
+        let dist1 = List(vec![List(vec![Integer(2)])]);
+        let dist2 = List(vec![List(vec![Integer(6)])]);
 
-    all_packets.push(dist1.clone());
-    all_packets.push(dist2.clone());
+        all_packets.push(dist1.clone());
+        all_packets.push(dist2.clone());
 
-    all_packets.sort_by(|a, b| match is_in_right_order(a, b) {
-        Some(first) => {
-            if first {
-                cmp::Ordering::Less
-            } else {
-                cmp::Ordering::Greater
+        all_packets.sort_by(|a, b| match is_in_right_order(a, b) {
+            Some(first) => {
+                if first {
+                    cmp::Ordering::Less
+                } else {
+                    cmp::Ordering::Greater
+                }
             }
-        }
-        None => cmp::Ordering::Equal,
-    });
-
-    let i1 = all_packets
-        .iter()
-        .position(|p| p == &dist1)
-        .expect("dist1 should be there") as u32
-        + 1;
-    let i2 = all_packets
-        .iter()
-        .position(|p| p == &dist2)
-        .expect("dist2 should be there") as u32
-        + 1;
-
-    Some(i1 * i2)
-}
-
-fn main() {
-    let input = &advent_of_code::read_file("inputs", 13);
-    advent_of_code::solve!(1, part_one, input);
-    advent_of_code::solve!(2, part_two, input);
+            None => cmp::Ordering::Equal,
+        });
+
+        let index = |divider: &Packet| {
+            all_packets
+                .iter()
+                .position(|p| p == divider)
+                .map(|i| i as u32 + 1)
+                .ok_or_else(|| Error::unsolvable("a divider packet went missing while sorting"))
+        };
+        let i1 = index(&dist1)?;
+        let i2 = index(&dist2)?;
+
+        Ok(Some(i1 * i2))
+    }
 }
 
 #[cfg(test)]
@@ -182,13 +175,13 @@ mod tests {
 
     #[test]
     fn test_part_one() {
-        let input = advent_of_code::read_file("examples", 13);
-        assert_eq!(part_one(&input), Some(13));
+        let input = Day13::parse(&advent_of_code::read_file(2022, "examples", 13)).unwrap();
+        assert_eq!(Day13::part_one(&input), Ok(Some(13)));
     }
 
     #[test]
     fn test_part_two() {
-        let input = advent_of_code::read_file("examples", 13);
-        assert_eq!(part_two(&input), Some(140));
+        let input = Day13::parse(&advent_of_code::read_file(2022, "examples", 13)).unwrap();
+        assert_eq!(Day13::part_two(&input), Ok(Some(140)));
     }
 }
@@ -0,0 +1,129 @@
+use std::{collections::HashSet, ops::RangeInclusive};
+
+use advent_of_code::{
+    helpers::{
+        grid::Point,
+        parse::{lines, parse_all, point},
+        ranges::RangeSet,
+    },
+    Error, Params,
+};
+use nom::{bytes::complete::tag, IResult};
+
+// Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+#[derive(Debug)]
+struct SensorAndClosestBeacon {
+    sensor: Point<i64>,
+    closest_beacon: Point<i64>,
+}
+
+fn parse_point(input: &str) -> IResult<&str, Point<i64>> {
+    let (input, (x, y)) = point(input)?;
+    Ok((input, Point::new(x, y)))
+}
+
+fn parse_single_input(input: &str) -> IResult<&str, SensorAndClosestBeacon> {
+    let (input, _) = tag("Sensor at ")(input)?;
+    let (input, sensor) = parse_point(input)?;
+    let (input, _) = tag(": closest beacon is at ")(input)?;
+    let (input, closest_beacon) = parse_point(input)?;
+    Ok((
+        input,
+        SensorAndClosestBeacon {
+            sensor,
+            closest_beacon,
+        },
+    ))
+}
+
+fn parse_inputs(input: &str) -> IResult<&str, Vec<SensorAndClosestBeacon>> {
+    lines(parse_single_input)(input)
+}
+
+impl SensorAndClosestBeacon {
+    /// The x positions on row `y` that are at least as close to the sensor as its beacon.
+    fn coverage(&self, y: i64) -> Option<RangeInclusive<i64>> {
+        let radius = self.sensor.manhattan(self.closest_beacon);
+        let reach = radius - (self.sensor.y - y).abs();
+        (reach >= 0).then(|| self.sensor.x - reach..=self.sensor.x + reach)
+    }
+}
+
+fn covered_on_row(sensors: &[SensorAndClosestBeacon], y: i64) -> RangeSet {
+    sensors.iter().filter_map(|s| s.coverage(y)).collect()
+}
+
+/// The row part one checks, and the largest x and y part two searches.
+pub struct Area {
+    row: i64,
+    max: i64,
+}
+
+impl Params for Area {
+    const INPUT: Area = Area {
+        row: 2_000_000,
+        max: 4_000_000,
+    };
+    const EXAMPLE: Area = Area { row: 10, max: 20 };
+}
+
+pub fn part_one(input: &str, area: &Area) -> Result<Option<u64>, Error> {
+    let sensors = parse_all(input, parse_inputs)?;
+    advent_of_code::debug!("{:?}", sensors);
+
+    let covered = covered_on_row(&sensors, area.row);
+    advent_of_code::debug!("covered on row {}: {:?}", area.row, covered.ranges());
+    let beacons_on_row: HashSet<Point<i64>> = sensors
+        .iter()
+        .map(|s| s.closest_beacon)
+        .filter(|b| b.y == area.row && covered.contains(b.x))
+        .collect();
+
+    Ok(Some(covered.len() - beacons_on_row.len() as u64))
+}
+
+pub fn part_two(input: &str, area: &Area) -> Result<Option<u64>, Error> {
+    let sensors = parse_all(input, parse_inputs)?;
+
+    for y in 0..=area.max {
+        let covered = covered_on_row(&sensors, y);
+        if covered.covers(0..=area.max) {
+            continue;
+        }
+        if let Some(gap) = covered.gaps(0..=area.max).ranges().first() {
+            let x = *gap.start();
+            return Ok(Some(x as u64 * 4_000_000 + y as u64));
+        }
+    }
+
+    Err(Error::unsolvable(
+        "every position can be reached by a sensor",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use advent_of_code::args::InputSource;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file(2022, "examples", 15);
+        assert_eq!(part_one(&input, &Area::EXAMPLE), Ok(Some(26)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file(2022, "examples", 15);
+        assert_eq!(part_two(&input, &Area::EXAMPLE), Ok(Some(56000011)));
+    }
+
+    #[test]
+    fn test_example_source() {
+        let source = InputSource::Example;
+        let input = source.read(2022, 15).unwrap();
+        let area: Area = advent_of_code::params(&source);
+        assert_eq!(part_one(&input, &area), Ok(Some(26)));
+        assert_eq!(part_two(&input, &area), Ok(Some(56000011)));
+    }
+}
@@ -0,0 +1,254 @@
+use std::collections::HashMap;
+
+use advent_of_code::{
+    helpers::parse::{lines, parse_all, signed},
+    Day, Error,
+};
+use nom::{
+    branch::alt,
+    character::complete::{alpha1, char, space1},
+    combinator::value,
+    sequence::pair,
+    IResult,
+};
+
+/// The monkey whose number is unknown in part two.
+const HUMAN: &str = "humn";
+
+#[derive(Debug)]
+pub enum Monkey {
+    Const(i32),
+    Operation {
+        first: String,
+        second: String,
+        op: Operator,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub enum Operator {
+    Plus,
+    Minus,
+    Multiply,
+    Divide,
+}
+
+fn parse_operator_monkey(input: &str) -> IResult<&str, (&str, Monkey)> {
+    let (input, (name, _)) = pair(alpha1, char(':'))(input)?;
+
+    let (input, _) = space1(input)?;
+    let (input, first) = alpha1(input)?;
+    let (input, _) = space1(input)?;
+    let (input, op) = alt((
+        value(Operator::Plus, char('+')),
+        value(Operator::Minus, char('-')),
+        value(Operator::Multiply, char('*')),
+        value(Operator::Divide, char('/')),
+    ))(input)?;
+    let (input, _) = space1(input)?;
+    let (input, second) = alpha1(input)?;
+
+    Ok((
+        input,
+        (
+            name,
+            Monkey::Operation {
+                first: first.to_owned(),
+                second: second.to_owned(),
+                op,
+            },
+        ),
+    ))
+}
+
+fn parse_const_monkey(input: &str) -> IResult<&str, (&str, Monkey)> {
+    let (input, (name, _)) = pair(alpha1, char(':'))(input)?;
+
+    let (input, (_, constant)) = pair(space1, signed)(input)?;
+
+    Ok((input, (name, Monkey::Const(constant))))
+}
+
+fn parse_monkeys(input: &str) -> IResult<&str, HashMap<&str, Monkey>> {
+    let (input, named_monkeys) = lines(alt((parse_operator_monkey, parse_const_monkey)))(input)?;
+
+    let mut result: HashMap<&str, Monkey> = HashMap::new();
+    for (name, monkey) in named_monkeys {
+        result.insert(name, monkey);
+    }
+
+    Ok((input, result))
+}
+
+/// `first op second`, or an error where it overflows or divides by zero.
+fn calculate(first: i64, op: &Operator, second: i64) -> Result<i64, Error> {
+    match op {
+        Operator::Plus => first.checked_add(second),
+        Operator::Minus => first.checked_sub(second),
+        Operator::Multiply => first.checked_mul(second),
+        Operator::Divide => first.checked_div(second),
+    }
+    .ok_or_else(|| {
+        Error::unsolvable(format!(
+            "{} {:?} {} overflows or divides by zero",
+            first, op, second
+        ))
+    })
+}
+
+fn get<'a>(monkeys: &'a HashMap<&str, Monkey>, name: &str) -> Result<&'a Monkey, Error> {
+    monkeys
+        .get(name)
+        .ok_or_else(|| Error::unsolvable(format!("there is no monkey named {}", name)))
+}
+
+fn solve_first(monkeys: &HashMap<&str, Monkey>, name: &str) -> Result<i64, Error> {
+    match get(monkeys, name)? {
+        Monkey::Const(c) => Ok(*c as i64),
+        Monkey::Operation { first, second, op } => {
+            let first_res = solve_first(monkeys, first)?;
+            let second_res = solve_first(monkeys, second)?;
+            calculate(first_res, op, second_res)
+        }
+    }
+}
+
+/// The number of `name`, or `None` when it depends on the human.
+fn try_solve(monkeys: &HashMap<&str, Monkey>, name: &str) -> Result<Option<i64>, Error> {
+    if name == HUMAN {
+        return Ok(None);
+    }
+
+    match get(monkeys, name)? {
+        Monkey::Const(c) => Ok(Some(*c as i64)),
+        Monkey::Operation { first, second, op } => {
+            let first_res = try_solve(monkeys, first)?;
+            let second_res = try_solve(monkeys, second)?;
+
+            match (first_res, second_res) {
+                (Some(first_res), Some(second_res)) => {
+                    calculate(first_res, op, second_res).map(Some)
+                }
+                _ => Ok(None),
+            }
+        }
+    }
+}
+
+fn solve_second(monkeys: &HashMap<&str, Monkey>, name: &str, expected: i64) -> Result<i64, Error> {
+    if name == HUMAN {
+        return Ok(expected);
+    }
+
+    match get(monkeys, name)? {
+        Monkey::Const(_) => Err(Error::unsolvable(format!(
+            "{} does not depend on {}",
+            name, HUMAN
+        ))),
+        Monkey::Operation { first, second, op } => {
+            let first_res = try_solve(monkeys, first)?;
+            let second_res = try_solve(monkeys, second)?;
+
+            match (first_res, second_res) {
+                (Some(first_res), None) => {
+                    let next_exp = match op {
+                        Operator::Plus => calculate(expected, &Operator::Minus, first_res),
+                        Operator::Minus => calculate(first_res, &Operator::Minus, expected),
+                        Operator::Multiply => calculate(expected, &Operator::Divide, first_res),
+                        Operator::Divide => calculate(first_res, &Operator::Divide, expected),
+                    }?;
+                    solve_second(monkeys, second, next_exp)
+                }
+                (None, Some(second_res)) => {
+                    let next_exp = match op {
+                        Operator::Plus => calculate(expected, &Operator::Minus, second_res),
+                        Operator::Minus => calculate(second_res, &Operator::Plus, expected),
+                        Operator::Multiply => calculate(expected, &Operator::Divide, second_res),
+                        Operator::Divide => calculate(second_res, &Operator::Multiply, expected),
+                    }?;
+                    solve_second(monkeys, first, next_exp)
+                }
+                (None, None) => Err(Error::unsolvable(format!(
+                    "both sides of {} depend on {}",
+                    name, HUMAN
+                ))),
+                (Some(_), Some(_)) => Err(Error::unsolvable(format!(
+                    "neither side of {} depends on {}",
+                    name, HUMAN
+                ))),
+            }
+        }
+    }
+}
+
+pub struct Day21;
+
+impl Day for Day21 {
+    type Input<'a> = HashMap<&'a str, Monkey>;
+    type PartOne = i64;
+    type PartTwo = i64;
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Error> {
+        parse_all(input, parse_monkeys)
+    }
+
+    fn part_one(monkeys: &Self::Input<'_>) -> Result<Option<i64>, Error> {
+        let res = solve_first(monkeys, "root")?;
+
+        Ok(Some(res))
+    }
+
+    fn part_two(monkeys: &Self::Input<'_>) -> Result<Option<i64>, Error> {
+        let root = monkeys
+            .get("root")
+            .ok_or_else(|| Error::unsolvable("there is no root monkey"))?;
+
+        if let Monkey::Operation {
+            first,
+            second,
+            op: _,
+        } = root
+        {
+            let second = solve_first(monkeys, second)?;
+            let first = solve_second(monkeys, first, second)?;
+
+            Ok(Some(first))
+        } else {
+            Err(Error::unsolvable("root monkey should be composite"))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file(2022, "examples", 21);
+        let monkeys = Day21::parse(&input).unwrap();
+        assert_eq!(Day21::part_one(&monkeys), Ok(Some(152)));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file(2022, "examples", 21);
+        let monkeys = Day21::parse(&input).unwrap();
+        assert_eq!(Day21::part_two(&monkeys), Ok(Some(301)));
+    }
+
+    #[test]
+    fn test_bad_input() {
+        let monkeys = Day21::parse("root: abcd + efgh\nabcd: 1").unwrap();
+        assert_eq!(
+            Day21::part_one(&monkeys),
+            Err(Error::unsolvable("there is no monkey named efgh"))
+        );
+
+        let monkeys = Day21::parse("root: abcd / efgh\nabcd: 1\nefgh: 0").unwrap();
+        assert!(Day21::part_one(&monkeys).is_err());
+
+        let monkeys = Day21::parse("root: abcd + efgh\nabcd: 1\nefgh: 2\nhumn: 3").unwrap();
+        assert!(Day21::part_two(&monkeys).is_err());
+    }
+}
@@ -0,0 +1,7 @@
+use advent_of_code::days::y{{year}}::day{{day_padded}}::*;
+
+fn main() {
+    let input = &advent_of_code::read_input({{year}}, {{day}});
+    advent_of_code::solve!({{year}}, {{day}}, 1, part_one, input);
+    advent_of_code::solve!({{year}}, {{day}}, 2, part_two, input);
+}
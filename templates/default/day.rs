@@ -0,0 +1,27 @@
+//! Day {{day}}: {{title}}, https://adventofcode.com/{{year}}/day/{{day}}
+use advent_of_code::Error;
+
+pub fn part_one(_input: &str) -> Result<Option<{{answer_one}}>, Error> {
+    Ok(None)
+}
+
+pub fn part_two(_input: &str) -> Result<Option<{{answer_two}}>, Error> {
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file({{year}}, "examples", {{day}});
+        assert_eq!(part_one(&input), Ok(None));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file({{year}}, "examples", {{day}});
+        assert_eq!(part_two(&input), Ok(None));
+    }
+}
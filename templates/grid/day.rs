@@ -0,0 +1,33 @@
+//! Day {{day}}: {{title}}, https://adventofcode.com/{{year}}/day/{{day}}
+use advent_of_code::{helpers::grid::Grid, Error};
+
+fn parse(input: &str) -> Result<Grid<char>, Error> {
+    Grid::parse(input, Some)
+}
+
+pub fn part_one(input: &str) -> Result<Option<{{answer_one}}>, Error> {
+    let _grid = parse(input)?;
+    Ok(None)
+}
+
+pub fn part_two(input: &str) -> Result<Option<{{answer_two}}>, Error> {
+    let _grid = parse(input)?;
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file({{year}}, "examples", {{day}});
+        assert_eq!(part_one(&input), Ok(None));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file({{year}}, "examples", {{day}});
+        assert_eq!(part_two(&input), Ok(None));
+    }
+}
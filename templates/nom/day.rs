@@ -0,0 +1,48 @@
+//! Day {{day}}: {{title}}, https://adventofcode.com/{{year}}/day/{{day}}
+use advent_of_code::{
+    helpers::parse::{parse_all, unsigned},
+    Error,
+};
+use nom::{character::complete::line_ending, multi::separated_list0, IResult};
+
+fn parse_line(input: &str) -> IResult<&str, u32> {
+    unsigned(input)
+}
+
+// Accepts an empty input, so the tests pass before the example is filled in.
+fn parse_input(input: &str) -> IResult<&str, Vec<u32>> {
+    separated_list0(line_ending, parse_line)(input)
+}
+
+pub fn part_one(input: &str) -> Result<Option<{{answer_one}}>, Error> {
+    let _items = parse_all(input, parse_input)?;
+    Ok(None)
+}
+
+pub fn part_two(input: &str) -> Result<Option<{{answer_two}}>, Error> {
+    let _items = parse_all(input, parse_input)?;
+    Ok(None)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_input() {
+        assert_eq!(parse_input("1\n2\n"), Ok(("\n", vec![1, 2])));
+        assert_eq!(parse_input(""), Ok(("", vec![])));
+    }
+
+    #[test]
+    fn test_part_one() {
+        let input = advent_of_code::read_file({{year}}, "examples", {{day}});
+        assert_eq!(part_one(&input), Ok(None));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = advent_of_code::read_file({{year}}, "examples", {{day}});
+        assert_eq!(part_two(&input), Ok(None));
+    }
+}
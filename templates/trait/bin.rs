@@ -0,0 +1,6 @@
+use advent_of_code::days::y{{year}}::day{{day_padded}}::Day{{day_padded}};
+
+fn main() {
+    let input = &advent_of_code::read_input({{year}}, {{day}});
+    advent_of_code::solve!({{year}}, {{day}}, Day{{day_padded}}, input);
+}
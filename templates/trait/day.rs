@@ -0,0 +1,45 @@
+//! Day {{day}}: {{title}}, https://adventofcode.com/{{year}}/day/{{day}}
+use advent_of_code::{Day, Error};
+
+pub struct Day{{day_padded}};
+
+impl Day for Day{{day_padded}} {
+    type Input<'a> = Vec<&'a str>;
+    type PartOne = {{answer_one}};
+    type PartTwo = {{answer_two}};
+
+    fn parse(input: &str) -> Result<Self::Input<'_>, Error> {
+        Ok(input.lines().collect())
+    }
+
+    fn part_one(_input: &Self::Input<'_>) -> Result<Option<Self::PartOne>, Error> {
+        Ok(None)
+    }
+
+    fn part_two(_input: &Self::Input<'_>) -> Result<Option<Self::PartTwo>, Error> {
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn example() -> String {
+        advent_of_code::read_file({{year}}, "examples", {{day}})
+    }
+
+    #[test]
+    fn test_part_one() {
+        let input = example();
+        let input = Day{{day_padded}}::parse(&input).unwrap();
+        assert_eq!(Day{{day_padded}}::part_one(&input), Ok(None));
+    }
+
+    #[test]
+    fn test_part_two() {
+        let input = example();
+        let input = Day{{day_padded}}::parse(&input).unwrap();
+        assert_eq!(Day{{day_padded}}::part_two(&input), Ok(None));
+    }
+}